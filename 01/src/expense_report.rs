@@ -0,0 +1,110 @@
+//! Finding entries of an expense report that add up to a given total.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+pub struct ExpenseReport {
+    /// All entries, sorted ascending.
+    entries: Vec<u32>,
+    /// The distinct entries, for looking up single values.
+    lookup: HashSet<u32>,
+}
+
+impl ExpenseReport {
+    pub fn new(mut entries: Vec<u32>) -> ExpenseReport {
+        entries.sort_unstable();
+        let lookup = entries.iter().copied().collect();
+
+        ExpenseReport { entries, lookup }
+    }
+
+    /// `k` different entries (by position, not by value) that add up to
+    /// `target`, in ascending order.
+    pub fn find_sum(&self, k: usize, target: u32) -> Option<Vec<u32>> {
+        match k {
+            0 if target == 0 => Some(Vec::new()),
+            0 => None,
+            1 if self.lookup.contains(&target) => Some(vec![target]),
+            1 => None,
+            k => k_sum(&self.entries, k, target),
+        }
+    }
+}
+
+/// Two pointers moving inwards from both ends of the sorted entries.
+fn pair_sum(entries: &[u32], target: u32) -> Option<(u32, u32)> {
+    if entries.len() < 2 {
+        return None;
+    }
+
+    let (mut low, mut high) = (0, entries.len() - 1);
+    while low < high {
+        let sum = entries[low] as u64 + entries[high] as u64;
+        match sum.cmp(&(target as u64)) {
+            Ordering::Less => low += 1,
+            Ordering::Greater => high -= 1,
+            Ordering::Equal => return Some((entries[low], entries[high])),
+        }
+    }
+
+    None
+}
+
+/// Fix the smallest entry of the result, then look for the remaining `k - 1`
+/// entries among the ones after it.
+fn k_sum(entries: &[u32], k: usize, target: u32) -> Option<Vec<u32>> {
+    if k == 2 {
+        return pair_sum(entries, target).map(|(a, b)| vec![a, b]);
+    }
+
+    for (i, &entry) in entries.iter().enumerate() {
+        // every other entry is at least as large as this one
+        if entry as u64 * k as u64 > target as u64 {
+            break;
+        }
+        // the same value again can't find anything new
+        if i > 0 && entries[i - 1] == entry {
+            continue;
+        }
+
+        if let Some(mut rest) = k_sum(&entries[i + 1..], k - 1, target - entry) {
+            rest.insert(0, entry);
+            return Some(rest);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_pairs_with_two_pointers() {
+        assert_eq!(pair_sum(&[1, 3, 5, 8], 11), Some((3, 8)));
+        assert_eq!(pair_sum(&[1, 3, 5, 8], 12), None);
+        assert_eq!(pair_sum(&[6], 12), None);
+    }
+
+    #[test]
+    fn uses_every_entry_once() {
+        let report = ExpenseReport::new(vec![1010, 5, 1000]);
+        assert_eq!(report.find_sum(2, 2020), None);
+
+        let report = ExpenseReport::new(vec![1010, 5, 1010]);
+        assert_eq!(report.find_sum(2, 2020), Some(vec![1010, 1010]));
+    }
+
+    #[test]
+    fn finds_any_number_of_entries() {
+        let report = ExpenseReport::new(vec![9, 1, 7, 3, 5]);
+        assert_eq!(report.find_sum(0, 0), Some(vec![]));
+        assert_eq!(report.find_sum(1, 7), Some(vec![7]));
+        assert_eq!(report.find_sum(1, 2), None);
+        assert_eq!(report.find_sum(3, 15), Some(vec![1, 5, 9]));
+        assert_eq!(report.find_sum(4, 24), Some(vec![3, 5, 7, 9]));
+        assert_eq!(report.find_sum(5, 25), Some(vec![1, 3, 5, 7, 9]));
+        assert_eq!(report.find_sum(6, 25), None);
+    }
+}
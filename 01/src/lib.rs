@@ -0,0 +1,77 @@
+use aoc_common::{Answer, Error, Solution};
+use expense_report::ExpenseReport;
+
+pub mod expense_report;
+
+const TARGET: u32 = 2020;
+
+/// Product of the `k` entries that add up to the target.
+fn solve(report: &ExpenseReport, k: usize) -> Result<Answer, Error> {
+    report
+        .find_sum(k, TARGET)
+        .map(|entries| entries.iter().map(|&e| e as u64).product::<u64>().into())
+        .ok_or_else(|| Error::NoSolution(format!("no {} entries add up to {}", k, TARGET)))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = ExpenseReport;
+
+    fn parse(input: &str) -> Result<ExpenseReport, Error> {
+        let entries = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| line.parse::<u32>().map_err(|why| Error::parse(i + 1, why)))
+            .collect::<Result<Vec<u32>, Error>>()?;
+
+        Ok(ExpenseReport::new(entries))
+    }
+
+    fn part1(report: &ExpenseReport) -> Result<Answer, Error> {
+        solve(report, 2)
+    }
+
+    fn part2(report: &ExpenseReport) -> Result<Answer, Error> {
+        solve(report, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = "1721
+979
+366
+299
+675
+1456";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day01>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(514579)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day01>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(241861950)
+        );
+    }
+
+    #[test]
+    fn reports_missing_pair() {
+        assert!(matches!(
+            solve_part::<Day01>("1\n2\n3", Part::One),
+            Err(Error::NoSolution(_))
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+use aoc_2020_01::Day01;
+
+fn main() {
+    aoc_common::run::<Day01>();
+}
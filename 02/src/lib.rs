@@ -0,0 +1,254 @@
+use aoc_common::{Answer, Error, Parsing, Rejected, Solution};
+use policy::{LetterCount, LetterPosition, PasswordPolicy};
+use std::fmt;
+use thiserror::Error;
+
+pub mod policy;
+
+/// A password and the policy it was created under.
+pub struct Entry {
+    pub min: usize,
+    pub max: usize,
+    pub letter: char,
+    pub password: String,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.min, self.max, self.letter, self.password
+        )
+    }
+}
+
+/// What's wrong with a malformed entry.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EntryErrorKind {
+    #[error("expected '{0}'")]
+    Missing(&'static str),
+    #[error("invalid number '{0}'")]
+    InvalidNumber(String),
+    #[error("expected a single letter, found '{0}'")]
+    InvalidLetter(String),
+    #[error("minimum {min} is larger than maximum {max}")]
+    InvertedRange { min: usize, max: usize },
+}
+
+/// A malformed entry; `line` and `column` start at 1.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line}, column {column}: {kind}")]
+pub struct EntryError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: EntryErrorKind,
+}
+
+/// Column of the byte offset `offset` in `line`, counting characters from 1.
+fn column(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+/// Parse an entry like `1-3 a: abcde`; errors carry the column but not the
+/// line.
+fn parse_line(line: &str) -> Result<Entry, (usize, EntryErrorKind)> {
+    let missing = |expected| (column(line, line.len()), EntryErrorKind::Missing(expected));
+
+    let separator = line.find(": ").ok_or_else(|| missing(": "))?;
+    let (policy, password) = (&line[..separator], &line[separator + 2..]);
+
+    let space = policy.find(' ').ok_or_else(|| missing(" "))?;
+    let (range, letter_str) = (&policy[..space], &policy[space + 1..]);
+
+    let dash = range.find('-').ok_or_else(|| missing("-"))?;
+    let (min_str, max_str) = (&range[..dash], &range[dash + 1..]);
+
+    let number = |s: &str, offset| {
+        s.parse::<usize>().map_err(|_| {
+            (
+                column(line, offset),
+                EntryErrorKind::InvalidNumber(s.to_owned()),
+            )
+        })
+    };
+    let min = number(min_str, 0)?;
+    let max = number(max_str, dash + 1)?;
+    if min > max {
+        return Err((1, EntryErrorKind::InvertedRange { min, max }));
+    }
+
+    let mut letters = letter_str.chars();
+    let letter = match (letters.next(), letters.next()) {
+        (Some(letter), None) => letter,
+        _ => {
+            return Err((
+                column(line, space + 1),
+                EntryErrorKind::InvalidLetter(letter_str.to_owned()),
+            ))
+        }
+    };
+
+    Ok(Entry {
+        min,
+        max,
+        letter,
+        password: password.to_owned(),
+    })
+}
+
+/// All well-formed entries, and the errors for the malformed ones.
+pub fn parse_entries(input: &str) -> (Vec<Entry>, Vec<EntryError>) {
+    let mut entries = Vec::new();
+    let mut rejected = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        match parse_line(line) {
+            Ok(entry) => entries.push(entry),
+            Err((column, kind)) => rejected.push(EntryError {
+                line: i + 1,
+                column,
+                kind,
+            }),
+        }
+    }
+
+    (entries, rejected)
+}
+
+/// The malformed entries as rejected lines.
+pub fn rejected_lines(errors: &[EntryError]) -> Vec<Rejected> {
+    errors
+        .iter()
+        .map(|error| Rejected::new(error.line, &error.kind).at_column(error.column))
+        .collect()
+}
+
+fn count_valid(entries: &[Entry], policy: impl PasswordPolicy) -> usize {
+    entries
+        .iter()
+        .filter(|entry| policy.is_valid(entry))
+        .count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Vec<Entry>, Error> {
+        let (entries, _) = Parsing::Strict.check(Self::parse_lenient(input)?)?;
+        Ok(entries)
+    }
+
+    fn parse_lenient(input: &str) -> Result<(Vec<Entry>, Vec<Rejected>), Error> {
+        let (entries, errors) = parse_entries(input);
+        Ok((entries, rejected_lines(&errors)))
+    }
+
+    fn part1(entries: &Vec<Entry>) -> Result<Answer, Error> {
+        Ok(count_valid(entries, LetterCount).into())
+    }
+
+    fn part2(entries: &Vec<Entry>) -> Result<Answer, Error> {
+        Ok(count_valid(entries, LetterPosition).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc";
+
+    #[test]
+    fn parses_entries() {
+        let entry = parse_line("1-3 a: abcde").unwrap();
+        assert_eq!((entry.min, entry.max), (1, 3));
+        assert_eq!(entry.letter, 'a');
+        assert_eq!(entry.password, "abcde");
+    }
+
+    #[test]
+    fn locates_malformed_entries() {
+        assert_eq!(
+            parse_line("1-3 a abcde").err(),
+            Some((12, EntryErrorKind::Missing(": ")))
+        );
+        assert_eq!(
+            parse_line("1-x a: abcde").err(),
+            Some((3, EntryErrorKind::InvalidNumber("x".to_owned())))
+        );
+        assert_eq!(
+            parse_line("1-3 : abcde").err(),
+            Some((5, EntryErrorKind::InvalidLetter("".to_owned())))
+        );
+        assert_eq!(
+            parse_line("1-3 ab: abcde").err(),
+            Some((5, EntryErrorKind::InvalidLetter("ab".to_owned())))
+        );
+        assert_eq!(
+            parse_line("4-3 a: abcde").err(),
+            Some((1, EntryErrorKind::InvertedRange { min: 4, max: 3 }))
+        );
+    }
+
+    #[test]
+    fn collects_rejected_lines() {
+        let (entries, rejected) = parse_entries("1-3 a: abcde\n1-3 a abcde\n2-9 c: ccccccccc");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            rejected,
+            vec![EntryError {
+                line: 2,
+                column: 12,
+                kind: EntryErrorKind::Missing(": ")
+            }]
+        );
+        assert_eq!(rejected[0].to_string(), "line 2, column 12: expected ': '");
+    }
+
+    #[test]
+    fn parses_strictly_or_leniently() {
+        let input = "1-3 a: abcde\n1-3 a abcde";
+        assert!(matches!(
+            Day02::parse(input),
+            Err(Error::Parse { line: 2, message }) if message == "column 12: expected ': '"
+        ));
+
+        let (entries, rejected) = Day02::parse_lenient(input).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            rejected,
+            vec![Rejected::new(2, "expected ': '").at_column(12)]
+        );
+    }
+
+    #[test]
+    fn zero_position_is_never_valid() {
+        let entry = parse_line("0-1 a: abc").unwrap();
+        assert!(!LetterPosition.is_valid(&entry));
+        assert!(LetterCount.is_valid(&entry));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day02>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(2)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day02>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(1)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! ```text
+//! aoc-2020-02 [--strict] [--format text|json] [-v|-vv]
+//! aoc-2020-02 audit [--strict] [--input PATH] [--at-least N LETTERS] [--pattern REGEX] [--positions P,...]
+//! ```
+//!
+//! `audit` checks every entry against both puzzle policies plus the ones
+//! given as arguments and reports which entries pass or fail each policy.
+//!
+//! Malformed entries are left out and listed at the end (or on stderr when
+//! solving). With `--strict`, any malformed entry fails the run instead.
+
+use aoc_2020_02::policy::{AtLeastOf, Pattern, Policies, PositionSet};
+use aoc_2020_02::Day02;
+use aoc_common::{rejected_summary, Error, Parsing, Solution};
+use regex::Regex;
+use std::env;
+use std::path::PathBuf;
+
+fn audit(args: &[String]) -> Result<String, Error> {
+    let mut parsing = Parsing::Lenient;
+    let mut input = PathBuf::from("input.txt");
+    let mut policies = Policies::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::InvalidArgument(format!("{} needs a value", arg)))
+        };
+
+        match arg.as_str() {
+            "--strict" => parsing = Parsing::Strict,
+            "--input" => input = PathBuf::from(value()?),
+            "--at-least" => {
+                let n = value()?
+                    .parse()
+                    .map_err(|why| Error::InvalidArgument(format!("--at-least: {}", why)))?;
+                let letters = value()?.chars().collect();
+                policies.register(AtLeastOf { n, letters });
+            }
+            "--pattern" => {
+                let regex = Regex::new(value()?)
+                    .map_err(|why| Error::InvalidArgument(format!("--pattern: {}", why)))?;
+                policies.register(Pattern(regex));
+            }
+            "--positions" => {
+                let positions = value()?
+                    .split(',')
+                    .map(|p| p.parse())
+                    .collect::<Result<_, _>>()
+                    .map_err(|why| Error::InvalidArgument(format!("--positions: {}", why)))?;
+                policies.register(PositionSet(positions));
+            }
+            _ => {
+                return Err(Error::InvalidArgument(format!(
+                    "unknown argument '{}'",
+                    arg
+                )))
+            }
+        }
+    }
+
+    let (entries, rejected) =
+        parsing.check(Day02::parse_lenient(&aoc_common::read_input(input)?)?)?;
+
+    let mut report = policies.report(&entries);
+    if !rejected.is_empty() {
+        report.push_str(&rejected_summary(&rejected));
+    }
+    Ok(report)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.split_first() {
+        Some((command, rest)) if command == "audit" => match audit(rest) {
+            Ok(report) => print!("{}", report),
+            Err(why) => aoc_common::fail(Day02::DAY, why),
+        },
+        _ => aoc_common::run_with_args::<Day02>(args.into_iter()),
+    }
+}
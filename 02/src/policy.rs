@@ -0,0 +1,229 @@
+//! Password policies and auditing entries against several of them.
+
+use crate::Entry;
+use regex::Regex;
+
+/// A rule that a password has to follow.
+pub trait PasswordPolicy {
+    /// short name used in audit reports
+    fn name(&self) -> String;
+
+    fn is_valid(&self, entry: &Entry) -> bool;
+}
+
+fn count_letter(string: &str, letter: char) -> usize {
+    string.chars().filter(|&c| c == letter).count()
+}
+
+/// Part I: the entry's letter occurs between `min` and `max` times.
+pub struct LetterCount;
+
+impl PasswordPolicy for LetterCount {
+    fn name(&self) -> String {
+        "count".to_owned()
+    }
+
+    fn is_valid(&self, entry: &Entry) -> bool {
+        let count = count_letter(&entry.password, entry.letter);
+        count >= entry.min && count <= entry.max
+    }
+}
+
+/// Part II: the entry's letter is at exactly one of the positions `min` and
+/// `max`, counting from 1.
+pub struct LetterPosition;
+
+impl PasswordPolicy for LetterPosition {
+    fn name(&self) -> String {
+        "position".to_owned()
+    }
+
+    fn is_valid(&self, entry: &Entry) -> bool {
+        // positions start at 1, so there's nothing at position 0
+        let char_at = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|i| entry.password.chars().nth(i))
+        };
+        if let (Some(a), Some(b)) = (char_at(entry.min), char_at(entry.max)) {
+            (a == entry.letter) != (b == entry.letter)
+        } else {
+            false
+        }
+    }
+}
+
+/// At least `n` of the listed letters occur in the password; the entry's
+/// own range and letter are ignored.
+pub struct AtLeastOf {
+    pub n: usize,
+    pub letters: Vec<char>,
+}
+
+impl PasswordPolicy for AtLeastOf {
+    fn name(&self) -> String {
+        format!("{} of {}", self.n, self.letters.iter().collect::<String>())
+    }
+
+    fn is_valid(&self, entry: &Entry) -> bool {
+        let present = self
+            .letters
+            .iter()
+            .filter(|&&letter| entry.password.contains(letter))
+            .count();
+        present >= self.n
+    }
+}
+
+/// The password matches a regular expression.
+pub struct Pattern(pub Regex);
+
+impl PasswordPolicy for Pattern {
+    fn name(&self) -> String {
+        format!("/{}/", self.0)
+    }
+
+    fn is_valid(&self, entry: &Entry) -> bool {
+        self.0.is_match(&entry.password)
+    }
+}
+
+/// The entry's letter is at exactly one of the given positions, counting
+/// from 1; part II with more than two positions.
+pub struct PositionSet(pub Vec<usize>);
+
+impl PasswordPolicy for PositionSet {
+    fn name(&self) -> String {
+        let positions: Vec<String> = self.0.iter().map(usize::to_string).collect();
+        format!("positions {}", positions.join(","))
+    }
+
+    fn is_valid(&self, entry: &Entry) -> bool {
+        let chars: Vec<char> = entry.password.chars().collect();
+        let matches = self
+            .0
+            .iter()
+            .filter(|&&position| position > 0 && chars.get(position - 1) == Some(&entry.letter))
+            .count();
+        matches == 1
+    }
+}
+
+/// Which policies an entry follows, in the order they were registered.
+pub struct Audit<'a> {
+    pub entry: &'a Entry,
+    pub valid: Vec<bool>,
+}
+
+/// A set of policies to audit entries against.
+pub struct Policies {
+    policies: Vec<Box<dyn PasswordPolicy>>,
+}
+
+impl Default for Policies {
+    /// The policies of both parts of the puzzle.
+    fn default() -> Policies {
+        let mut policies = Policies::new();
+        policies.register(LetterCount).register(LetterPosition);
+        policies
+    }
+}
+
+impl Policies {
+    pub fn new() -> Policies {
+        Policies {
+            policies: Vec::new(),
+        }
+    }
+
+    pub fn register(&mut self, policy: impl PasswordPolicy + 'static) -> &mut Policies {
+        self.policies.push(Box::new(policy));
+        self
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.policies.iter().map(|policy| policy.name()).collect()
+    }
+
+    pub fn audit<'a>(&self, entries: &'a [Entry]) -> Vec<Audit<'a>> {
+        entries
+            .iter()
+            .map(|entry| Audit {
+                entry,
+                valid: self
+                    .policies
+                    .iter()
+                    .map(|policy| policy.is_valid(entry))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// One line per entry with pass/FAIL for each policy, followed by how
+    /// many entries passed each policy.
+    pub fn report(&self, entries: &[Entry]) -> String {
+        let audits = self.audit(entries);
+        let names = self.names();
+
+        let mut report = format!("entry\t{}\n", names.join("\t"));
+        for audit in &audits {
+            let results: Vec<&str> = audit
+                .valid
+                .iter()
+                .map(|&valid| if valid { "pass" } else { "FAIL" })
+                .collect();
+            report.push_str(&format!("{}\t{}\n", audit.entry, results.join("\t")));
+        }
+
+        for (i, name) in names.iter().enumerate() {
+            let passed = audits.iter().filter(|audit| audit.valid[i]).count();
+            report.push_str(&format!("{}: {} of {} pass\n", name, passed, audits.len()));
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(line: &str) -> Entry {
+        crate::parse_line(line).unwrap()
+    }
+
+    #[test]
+    fn checks_custom_policies() {
+        let entry = entry("1-3 a: abcde");
+        assert!(AtLeastOf {
+            n: 2,
+            letters: vec!['a', 'e', 'z']
+        }
+        .is_valid(&entry));
+        assert!(!AtLeastOf {
+            n: 2,
+            letters: vec!['x', 'y', 'z']
+        }
+        .is_valid(&entry));
+        assert!(Pattern(Regex::new("^ab.*e$").unwrap()).is_valid(&entry));
+        assert!(PositionSet(vec![1, 2, 4]).is_valid(&entry));
+        assert!(!PositionSet(vec![0, 2, 4]).is_valid(&entry));
+    }
+
+    #[test]
+    fn reports_each_policy() {
+        let entries = vec![entry("1-3 a: abcde"), entry("1-3 b: cdefg")];
+        let mut policies = Policies::default();
+        policies.register(PositionSet(vec![3]));
+
+        assert_eq!(
+            policies.report(&entries),
+            "entry\tcount\tposition\tpositions 3\n\
+             1-3 a: abcde\tpass\tpass\tFAIL\n\
+             1-3 b: cdefg\tFAIL\tFAIL\tFAIL\n\
+             count: 1 of 2 pass\n\
+             position: 1 of 2 pass\n\
+             positions 3: 0 of 2 pass\n"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_common::{Answer, Error, Solution};
+use map::Map;
+
+pub mod map;
+pub mod slope;
+
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Map, Error> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Map) -> Result<Answer, Error> {
+        Ok(map.check_slope(3, 1).into())
+    }
+
+    fn part2(map: &Map) -> Result<Answer, Error> {
+        let product: usize = SLOPES
+            .iter()
+            .map(|&(right, down)| map.check_slope(right, down))
+            .product();
+
+        Ok(product.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn checks_slopes() {
+        let map = Day03::parse(EXAMPLE).unwrap();
+        let counts: Vec<usize> = SLOPES
+            .iter()
+            .map(|&(right, down)| map.check_slope(right, down))
+            .collect();
+        assert_eq!(counts, vec![2, 7, 3, 4, 2]);
+        assert_eq!(map.path(3, 1)[4], (1, 4));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day03>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(7)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day03>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(336)
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! ```text
+//! aoc-2020-03 [--format text|json] [-v|-vv]
+//! aoc-2020-03 slopes SLOPE... [--input PATH]
+//! aoc-2020-03 search [--right N] [--down N] [--fewest|--most] [--input PATH]
+//! aoc-2020-03 render SLOPE [--input PATH]
+//! ```
+//!
+//! Slopes are given as `RIGHT/DOWN`, e.g. `3/1`. `slopes` counts the trees
+//! on each given slope and their product. `search` tries every slope going
+//! 1 to `--right` (default 10) right and 1 to `--down` (default 2) down and
+//! prints the one with the fewest (default) or most trees. `render` prints
+//! the map with the path marked like in the puzzle text.
+
+use aoc_2020_03::map::{Goal, Map};
+use aoc_2020_03::slope::Slope;
+use aoc_2020_03::Day03;
+use aoc_common::{Error, Solution};
+use std::env;
+use std::path::PathBuf;
+
+struct Options {
+    input: PathBuf,
+    slopes: Vec<Slope>,
+    max_right: usize,
+    max_down: usize,
+    goal: Goal,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, Error> {
+    let mut options = Options {
+        input: PathBuf::from("input.txt"),
+        slopes: Vec::new(),
+        max_right: 10,
+        max_down: 2,
+        goal: Goal::FewestTrees,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::InvalidArgument(format!("{} needs a value", arg)))
+        };
+        let number = |value: &str| {
+            value
+                .parse()
+                .map_err(|why| Error::InvalidArgument(format!("{}: {}", arg, why)))
+        };
+
+        match arg.as_str() {
+            "--input" => options.input = PathBuf::from(value()?),
+            "--right" => options.max_right = number(value()?)?,
+            "--down" => options.max_down = number(value()?)?,
+            "--fewest" => options.goal = Goal::FewestTrees,
+            "--most" => options.goal = Goal::MostTrees,
+            slope => options.slopes.push(slope.parse()?),
+        }
+    }
+
+    Ok(options)
+}
+
+fn load_map(options: &Options) -> Result<Map, Error> {
+    Day03::parse(&aoc_common::read_input(&options.input)?)
+}
+
+fn slopes(options: &Options) -> Result<String, Error> {
+    if options.slopes.is_empty() {
+        return Err(Error::InvalidArgument(
+            "slopes needs at least one slope".to_owned(),
+        ));
+    }
+
+    let map = load_map(options)?;
+    let mut output = String::new();
+    let mut product: usize = 1;
+    for slope in &options.slopes {
+        let trees = map.check_slope(slope.right, slope.down);
+        output.push_str(&format!("{}: {} trees\n", slope, trees));
+        product = product.checked_mul(trees).ok_or_else(|| {
+            Error::InvalidInput("the product of the tree counts is too large".to_owned())
+        })?;
+    }
+    output.push_str(&format!("product: {}\n", product));
+
+    Ok(output)
+}
+
+fn search(options: &Options) -> Result<String, Error> {
+    if !options.slopes.is_empty() {
+        return Err(Error::InvalidArgument(
+            "search doesn't take slopes".to_owned(),
+        ));
+    }
+
+    let map = load_map(options)?;
+    let (slope, trees) = map
+        .search(options.max_right, options.max_down, options.goal)
+        .ok_or_else(|| {
+            Error::InvalidArgument("--right and --down must be at least 1".to_owned())
+        })?;
+
+    Ok(format!("{}: {} trees\n", slope, trees))
+}
+
+fn render(options: &Options) -> Result<String, Error> {
+    match options.slopes[..] {
+        [slope] => Ok(load_map(options)?.render(slope.right, slope.down)),
+        _ => Err(Error::InvalidArgument(
+            "render needs exactly one slope".to_owned(),
+        )),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command: fn(&Options) -> Result<String, Error> = match args.first().map(String::as_str) {
+        Some("slopes") => slopes,
+        Some("search") => search,
+        Some("render") => render,
+        _ => return aoc_common::run::<Day03>(),
+    };
+
+    match parse_options(&args[1..]).and_then(|options| command(&options)) {
+        Ok(output) => print!("{}", output),
+        Err(why) => aoc_common::fail(Day03::DAY, why),
+    }
+}
@@ -0,0 +1,213 @@
+//! The toboggan map, which repeats to the right forever.
+
+use crate::slope::Slope;
+use aoc_common::Error;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Open,
+    Tree,
+}
+
+impl Tile {
+    fn parse(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Open),
+            '#' => Some(Tile::Tree),
+            _ => None,
+        }
+    }
+}
+
+/// Which slopes `Map::search` looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    FewestTrees,
+    MostTrees,
+}
+
+/// A grid of tiles that wraps around horizontally.
+#[derive(Debug)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    /// row by row
+    tiles: Vec<Tile>,
+}
+
+impl Map {
+    /// Parse rows of `.` and `#`; all rows must have the same width.
+    pub fn parse(input: &str) -> Result<Map, Error> {
+        let mut width = None;
+        let mut tiles = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+            match width {
+                None if row_width == 0 => return Err(Error::parse(i + 1, "empty row")),
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(Error::parse(
+                        i + 1,
+                        format!("row is {} tiles wide instead of {}", row_width, width),
+                    ))
+                }
+                Some(_) => (),
+            }
+
+            for (column, c) in line.chars().enumerate() {
+                let tile = Tile::parse(c).ok_or_else(|| {
+                    Error::parse(
+                        i + 1,
+                        format!("column {}: unknown tile '{}'", column + 1, c),
+                    )
+                })?;
+                tiles.push(tile);
+            }
+        }
+
+        let width = width.ok_or_else(|| Error::InvalidInput("empty map".to_owned()))?;
+        Ok(Map {
+            width,
+            height: tiles.len() / width,
+            tiles,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The tile at `x` (wrapping around) and `y`, or `None` below the map.
+    pub fn get(&self, x: usize, y: usize) -> Option<Tile> {
+        if y < self.height {
+            Some(self.tiles[y * self.width + x % self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Coordinates visited going `right` and `down` from the top left
+    /// corner until the bottom, without wrapping `x`.
+    ///
+    /// Panics if `down` is 0.
+    fn steps(&self, right: usize, down: usize) -> impl Iterator<Item = (usize, usize)> {
+        (0..self.height)
+            .step_by(down)
+            .enumerate()
+            .map(move |(step, y)| (step * right, y))
+    }
+
+    /// Coordinates visited going `right` and `down` from the top left
+    /// corner until the bottom, with `x` wrapped into the map.
+    ///
+    /// Panics if `down` is 0.
+    pub fn path(&self, right: usize, down: usize) -> Vec<(usize, usize)> {
+        self.steps(right, down)
+            .map(|(x, y)| (x % self.width, y))
+            .collect()
+    }
+
+    /// Number of trees on the path going `right` and `down`.
+    pub fn check_slope(&self, right: usize, down: usize) -> usize {
+        self.path(right, down)
+            .into_iter()
+            .filter(|&(x, y)| self.get(x, y) == Some(Tile::Tree))
+            .count()
+    }
+
+    /// The slope with the fewest or most trees among all slopes going 1 to
+    /// `max_right` right and 1 to `max_down` down, preferring smaller steps on
+    /// ties.
+    pub fn search(&self, max_right: usize, max_down: usize, goal: Goal) -> Option<(Slope, usize)> {
+        let candidates = (1..=max_down)
+            .flat_map(|down| (1..=max_right).map(move |right| Slope { right, down }))
+            .map(|slope| (slope, self.check_slope(slope.right, slope.down)));
+
+        match goal {
+            Goal::FewestTrees => candidates.min_by_key(|&(_, trees)| trees),
+            Goal::MostTrees => candidates.rev().max_by_key(|&(_, trees)| trees),
+        }
+    }
+
+    /// The map like in the puzzle text, repeated to the right as far as the
+    /// path goes, with visited open squares as `O` and trees hit as `X`.
+    ///
+    /// Panics if `down` is 0.
+    pub fn render(&self, right: usize, down: usize) -> String {
+        let visited: HashSet<(usize, usize)> = self.steps(right, down).collect();
+        let width = visited.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let repeats = width.div_ceil(self.width);
+
+        let mut rendered = String::with_capacity((repeats * self.width + 1) * self.height);
+        for y in 0..self.height {
+            for x in 0..repeats * self.width {
+                let tile = self.tiles[y * self.width + x % self.width];
+                rendered.push(match (visited.contains(&(x, y)), tile) {
+                    (false, Tile::Open) => '.',
+                    (false, Tile::Tree) => '#',
+                    (true, Tile::Open) => 'O',
+                    (true, Tile::Tree) => 'X',
+                });
+            }
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_horizontally() {
+        let map = Map::parse("#..\n.#.").unwrap();
+        assert_eq!((map.width(), map.height()), (3, 2));
+        assert_eq!(map.get(0, 0), Some(Tile::Tree));
+        assert_eq!(map.get(3, 0), Some(Tile::Tree));
+        assert_eq!(map.get(4, 1), Some(Tile::Tree));
+        assert_eq!(map.get(0, 2), None);
+        assert_eq!(map.path(2, 1), vec![(0, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn rejects_malformed_maps() {
+        assert!(matches!(
+            Map::parse("#..\n.#"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            Map::parse("#..\n.O."),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(Map::parse(""), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn renders_path() {
+        let map = Map::parse("..#\n#..\n.#.").unwrap();
+        assert_eq!(map.render(2, 1), "O.#..#\n#.O#..\n.#..X.\n");
+        assert_eq!(map.render(1, 2), "O.#\n#..\n.X.\n");
+    }
+
+    #[test]
+    fn searches_slopes() {
+        let map = Map::parse("..#\n#..\n.#.").unwrap();
+        // going 2 or 3 right hits a tree, going 1 or 4 right doesn't
+        assert_eq!(
+            map.search(4, 1, Goal::MostTrees),
+            Some((Slope { right: 2, down: 1 }, 1))
+        );
+        assert_eq!(
+            map.search(4, 1, Goal::FewestTrees),
+            Some((Slope { right: 1, down: 1 }, 0))
+        );
+    }
+}
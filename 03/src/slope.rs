@@ -0,0 +1,52 @@
+//! Slopes given as `RIGHT/DOWN`, e.g. `3/1`.
+
+use aoc_common::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.right, self.down)
+    }
+}
+
+impl FromStr for Slope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Slope, Error> {
+        let invalid =
+            || Error::InvalidArgument(format!("invalid slope '{}', expected RIGHT/DOWN", s));
+
+        let (right, down) = s.split_once('/').ok_or_else(invalid)?;
+        let right = right.parse().map_err(|_| invalid())?;
+        let down = down.parse().map_err(|_| invalid())?;
+        if down == 0 {
+            return Err(Error::InvalidArgument(format!(
+                "invalid slope '{}', must go down at least 1",
+                s
+            )));
+        }
+
+        Ok(Slope { right, down })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_slopes() {
+        assert_eq!("3/1".parse::<Slope>().unwrap(), Slope { right: 3, down: 1 });
+        assert_eq!("0/2".parse::<Slope>().unwrap(), Slope { right: 0, down: 2 });
+        assert!("3/0".parse::<Slope>().is_err());
+        assert!("3".parse::<Slope>().is_err());
+        assert!("-1/1".parse::<Slope>().is_err());
+    }
+}
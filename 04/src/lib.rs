@@ -0,0 +1,161 @@
+use aoc_common::{Answer, Error, Solution};
+use passport::Passport;
+use schema::Schema;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+pub mod passport;
+pub mod report;
+pub mod schema;
+
+pub type Document = HashMap<String, String>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Document>;
+
+    /// Parse the blank-line separated documents into their `key:value`
+    /// properties.
+    fn parse(input: &str) -> Result<Vec<Document>, Error> {
+        let mut documents = Vec::new();
+        let mut document = Document::with_capacity(8);
+
+        for (i, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                if !document.is_empty() {
+                    documents.push(document);
+                    document = Document::with_capacity(8);
+                }
+                continue;
+            }
+
+            for property in line.split_whitespace() {
+                let (key, value) = property
+                    .split_once(':')
+                    .filter(|(key, value)| {
+                        !key.is_empty() && !value.is_empty() && !value.contains(':')
+                    })
+                    .ok_or_else(|| {
+                        Error::parse(i + 1, format!("invalid property '{}'", property))
+                    })?;
+
+                if document.insert(key.to_owned(), value.to_owned()).is_some() {
+                    return Err(Error::parse(i + 1, format!("duplicate key '{}'", key)));
+                }
+            }
+        }
+
+        if !document.is_empty() {
+            documents.push(document);
+        }
+
+        Ok(documents)
+    }
+
+    fn part1(documents: &Vec<Document>) -> Result<Answer, Error> {
+        let schema = Schema::passport();
+        Ok(documents
+            .iter()
+            .filter(|document| schema.has_required_fields(document))
+            .count()
+            .into())
+    }
+
+    /// Documents whose fields parse into a `Passport` and whose values the
+    /// passport schema allows.
+    fn part2(documents: &Vec<Document>) -> Result<Answer, Error> {
+        let schema = Schema::passport();
+        Ok(documents
+            .iter()
+            .filter(|document| Passport::try_from(*document).is_ok() && schema.is_valid(document))
+            .count()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+    const INVALID: &str = "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007";
+
+    const VALID: &str = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+
+    #[test]
+    fn rejects_malformed_properties() {
+        for document in &["byr:1937 iyr", "byr:", ":1937", "byr:19:37"] {
+            assert!(
+                matches!(Day04::parse(document), Err(Error::Parse { line: 1, .. })),
+                "{}",
+                document
+            );
+        }
+        assert!(matches!(
+            Day04::parse("byr:1937\nbyr:1938"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        // the same key in different documents is fine
+        assert!(Day04::parse("byr:1937\n\nbyr:1938").is_ok());
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day04>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(2)
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            solve_part::<Day04>(INVALID, Part::Two).unwrap(),
+            Answer::Unsigned(0)
+        );
+        assert_eq!(
+            solve_part::<Day04>(VALID, Part::Two).unwrap(),
+            Answer::Unsigned(4)
+        );
+    }
+}
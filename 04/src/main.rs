@@ -0,0 +1,101 @@
+//! ```text
+//! aoc-2020-04 [--format text|json] [-v|-vv]
+//! aoc-2020-04 validate [--schema PATH] [--input PATH] [--format text|json]
+//! aoc-2020-04 report [--schema PATH] [--input PATH] [--format text|json]
+//! ```
+//!
+//! `validate` checks the documents against a schema file (see
+//! `passport.toml`) instead of the puzzle's rules. `report` lists every
+//! missing field and broken rule of each invalid document. With
+//! `--format json`, `validate` prints its counts as one line of JSON and
+//! `report` prints one line of JSON per document.
+
+use aoc_2020_04::schema::Schema;
+use aoc_2020_04::{report, Day04, Document};
+use aoc_common::{Error, Format, Solution};
+use std::env;
+use std::path::PathBuf;
+
+struct Options {
+    input: PathBuf,
+    schema: Schema,
+    format: Format,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, Error> {
+    let mut options = Options {
+        input: PathBuf::from("input.txt"),
+        schema: Schema::passport().clone(),
+        format: Format::Text,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| Error::InvalidArgument(format!("{} needs a value", arg)))?;
+
+        match arg.as_str() {
+            "--input" => options.input = PathBuf::from(value),
+            "--schema" => {
+                let text = aoc_common::read_input(value)?;
+                options.schema = Schema::from_toml(&text)
+                    .map_err(|why| Error::InvalidArgument(format!("{}: {}", value, why)))?;
+            }
+            "--format" => options.format = value.parse()?,
+            _ => {
+                return Err(Error::InvalidArgument(format!(
+                    "unknown argument '{}'",
+                    arg
+                )))
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+fn load_documents(options: &Options) -> Result<Vec<Document>, Error> {
+    Day04::parse(&aoc_common::read_input(&options.input)?)
+}
+
+fn validate(options: &Options) -> Result<String, Error> {
+    let documents = load_documents(options)?;
+    let valid = documents
+        .iter()
+        .filter(|d| options.schema.is_valid(d))
+        .count();
+
+    Ok(match options.format {
+        Format::Text => format!("{} of {} documents valid\n", valid, documents.len()),
+        Format::Json => format!(
+            "{{\"valid\":{},\"documents\":{}}}\n",
+            valid,
+            documents.len()
+        ),
+    })
+}
+
+fn report(options: &Options) -> Result<String, Error> {
+    let reports = report::validate(&options.schema, &load_documents(options)?);
+
+    Ok(match options.format {
+        Format::Text => report::to_text(&reports),
+        Format::Json => report::to_json(&reports),
+    })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command: fn(&Options) -> Result<String, Error> = match args.first().map(String::as_str) {
+        Some("validate") => validate,
+        Some("report") => report,
+        _ => return aoc_common::run::<Day04>(),
+    };
+
+    match parse_options(&args[1..]).and_then(|options| command(&options)) {
+        Ok(output) => print!("{}", output),
+        Err(why) => aoc_common::fail(Day04::DAY, why),
+    }
+}
@@ -0,0 +1,267 @@
+//! Passports with typed fields.
+//!
+//! These types only know how each field is written; which values are
+//! allowed is up to the schema. Part II counts the documents that parse
+//! into a `Passport` and that `Schema::passport()` accepts.
+
+use crate::Document;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A field's value isn't written the way its type needs.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("expected {expected}")]
+pub struct FieldError {
+    pub expected: &'static str,
+}
+
+fn expected<T>(expected: &'static str) -> Result<T, FieldError> {
+    Err(FieldError { expected })
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PassportError {
+    #[error("missing field '{0}'")]
+    Missing(&'static str),
+    #[error("invalid {field} '{value}': {source}")]
+    Invalid {
+        field: &'static str,
+        value: String,
+        source: FieldError,
+    },
+}
+
+/// Whether `s` is a non-empty run of ASCII digits, so no sign either.
+fn all_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// A four-digit year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Year(pub u16);
+
+impl FromStr for Year {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<Year, FieldError> {
+        match s.parse() {
+            Ok(year) if s.len() == 4 && all_digits(s) => Ok(Year(year)),
+            _ => expected("four digits"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Height {
+    Centimeters(u16),
+    Inches(u16),
+}
+
+impl FromStr for Height {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<Height, FieldError> {
+        let number = |n: &str| match n.parse::<u16>() {
+            Ok(number) if all_digits(n) => Ok(number),
+            _ => expected("a number followed by 'cm' or 'in'"),
+        };
+
+        if let Some(n) = s.strip_suffix("cm") {
+            Ok(Height::Centimeters(number(n)?))
+        } else if let Some(n) = s.strip_suffix("in") {
+            Ok(Height::Inches(number(n)?))
+        } else {
+            expected("a number followed by 'cm' or 'in'")
+        }
+    }
+}
+
+/// A colour written as `#rrggbb` in lowercase hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HairColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl FromStr for HairColor {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<HairColor, FieldError> {
+        let hex = match s.strip_prefix('#') {
+            Some(hex)
+                if hex.len() == 6 && hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')) =>
+            {
+                hex
+            }
+            _ => return expected("'#' and six lowercase hex digits"),
+        };
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+        Ok(HairColor {
+            r: channel(0),
+            g: channel(2),
+            b: channel(4),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EyeColor {
+    Amber,
+    Blue,
+    Brown,
+    Gray,
+    Green,
+    Hazel,
+    Other,
+}
+
+impl FromStr for EyeColor {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<EyeColor, FieldError> {
+        match s {
+            "amb" => Ok(EyeColor::Amber),
+            "blu" => Ok(EyeColor::Blue),
+            "brn" => Ok(EyeColor::Brown),
+            "gry" => Ok(EyeColor::Gray),
+            "grn" => Ok(EyeColor::Green),
+            "hzl" => Ok(EyeColor::Hazel),
+            "oth" => Ok(EyeColor::Other),
+            _ => expected("amb, blu, brn, gry, grn, hzl or oth"),
+        }
+    }
+}
+
+/// A nine-digit number, including leading zeroes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassportId(String);
+
+impl PassportId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PassportId {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<PassportId, FieldError> {
+        if s.len() == 9 && all_digits(s) {
+            Ok(PassportId(s.to_owned()))
+        } else {
+            expected("nine digits")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Passport {
+    pub birth_year: Year,
+    pub issue_year: Year,
+    pub expiration_year: Year,
+    pub height: Height,
+    pub hair_color: HairColor,
+    pub eye_color: EyeColor,
+    pub passport_id: PassportId,
+    /// not required, and any value goes
+    pub country_id: Option<String>,
+}
+
+fn field<T>(document: &Document, key: &'static str) -> Result<T, PassportError>
+where
+    T: FromStr<Err = FieldError>,
+{
+    let value = document.get(key).ok_or(PassportError::Missing(key))?;
+    value.parse().map_err(|source| PassportError::Invalid {
+        field: key,
+        value: value.clone(),
+        source,
+    })
+}
+
+impl TryFrom<&Document> for Passport {
+    type Error = PassportError;
+
+    fn try_from(document: &Document) -> Result<Passport, PassportError> {
+        Ok(Passport {
+            birth_year: field(document, "byr")?,
+            issue_year: field(document, "iyr")?,
+            expiration_year: field(document, "eyr")?,
+            height: field(document, "hgt")?,
+            hair_color: field(document, "hcl")?,
+            eye_color: field(document, "ecl")?,
+            passport_id: field(document, "pid")?,
+            country_id: document.get("cid").cloned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fields() {
+        assert_eq!("2002".parse(), Ok(Year(2002)));
+        assert!("02002".parse::<Year>().is_err());
+        assert!("+200".parse::<Year>().is_err());
+        assert_eq!("60in".parse(), Ok(Height::Inches(60)));
+        assert_eq!("190cm".parse(), Ok(Height::Centimeters(190)));
+        assert!("190".parse::<Height>().is_err());
+        assert!("+90cm".parse::<Height>().is_err());
+        assert_eq!(
+            "#123abc".parse(),
+            Ok(HairColor {
+                r: 0x12,
+                g: 0x3a,
+                b: 0xbc
+            })
+        );
+        assert!("#123abz".parse::<HairColor>().is_err());
+        assert!("123abc".parse::<HairColor>().is_err());
+        assert_eq!("brn".parse(), Ok(EyeColor::Brown));
+        assert!("wat".parse::<EyeColor>().is_err());
+        assert_eq!(
+            "000000001".parse::<PassportId>().unwrap().as_str(),
+            "000000001"
+        );
+        assert_eq!(
+            "0123456789".parse::<PassportId>(),
+            Err(FieldError {
+                expected: "nine digits"
+            })
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_invalid_fields() {
+        let document: Document = [("byr", "1937"), ("iyr", "2017"), ("eyr", "2020")]
+            .iter()
+            .map(|&(k, v)| (k.to_owned(), v.to_owned()))
+            .collect();
+        assert_eq!(
+            Passport::try_from(&document),
+            Err(PassportError::Missing("hgt"))
+        );
+
+        let mut document = document;
+        document.insert("hgt".to_owned(), "183".to_owned());
+        assert_eq!(
+            Passport::try_from(&document),
+            Err(PassportError::Invalid {
+                field: "hgt",
+                value: "183".to_owned(),
+                source: FieldError {
+                    expected: "a number followed by 'cm' or 'in'"
+                }
+            })
+        );
+        assert_eq!(
+            Passport::try_from(&document).unwrap_err().to_string(),
+            "invalid hgt '183': expected a number followed by 'cm' or 'in'"
+        );
+    }
+}
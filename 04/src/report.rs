@@ -0,0 +1,101 @@
+//! Which documents of a batch are invalid, and why.
+
+use crate::schema::{Schema, Violation};
+use crate::Document;
+use aoc_common::json_string;
+
+/// The violations of one document; `position` starts at 1.
+pub struct DocumentReport {
+    pub position: usize,
+    pub violations: Vec<Violation>,
+}
+
+pub fn validate(schema: &Schema, documents: &[Document]) -> Vec<DocumentReport> {
+    documents
+        .iter()
+        .enumerate()
+        .map(|(i, document)| DocumentReport {
+            position: i + 1,
+            violations: schema.violations(document),
+        })
+        .collect()
+}
+
+/// The invalid documents with their violations, one per line, and how many
+/// documents are valid.
+pub fn to_text(reports: &[DocumentReport]) -> String {
+    let mut text = String::new();
+    for report in reports.iter().filter(|r| !r.violations.is_empty()) {
+        text.push_str(&format!("document {}:\n", report.position));
+        for violation in &report.violations {
+            text.push_str(&format!("  {}\n", violation));
+        }
+    }
+
+    let valid = reports.iter().filter(|r| r.violations.is_empty()).count();
+    text.push_str(&format!("{} of {} documents valid\n", valid, reports.len()));
+
+    text
+}
+
+fn violation_to_json(violation: &Violation) -> String {
+    match violation {
+        Violation::Missing { field } => {
+            format!("{{\"field\":{},\"missing\":true}}", json_string(field))
+        }
+        Violation::Broken { field, value, rule } => format!(
+            "{{\"field\":{},\"value\":{},\"rule\":{}}}",
+            json_string(field),
+            json_string(value),
+            json_string(rule)
+        ),
+    }
+}
+
+/// Every document as one line of JSON.
+pub fn to_json(reports: &[DocumentReport]) -> String {
+    reports
+        .iter()
+        .map(|report| {
+            let violations: Vec<String> = report.violations.iter().map(violation_to_json).collect();
+            format!(
+                "{{\"document\":{},\"valid\":{},\"violations\":[{}]}}\n",
+                report.position,
+                report.violations.is_empty(),
+                violations.join(",")
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use aoc_common::Solution;
+
+    const BATCH: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929 hgt:59cm";
+
+    #[test]
+    fn reports_invalid_documents() {
+        let documents = Day04::parse(BATCH).unwrap();
+        let reports = validate(Schema::passport(), &documents);
+
+        assert_eq!(
+            to_text(&reports),
+            "document 2:\n  \
+             hgt: '59cm' breaks units 150-193 cm or 59-76 in\n\
+             1 of 2 documents valid\n"
+        );
+        assert_eq!(
+            to_json(&reports),
+            "{\"document\":1,\"valid\":true,\"violations\":[]}\n\
+             {\"document\":2,\"valid\":false,\"violations\":[\
+             {\"field\":\"hgt\",\"value\":\"59cm\",\"rule\":\"units 150-193 cm or 59-76 in\"}]}\n"
+        );
+    }
+}
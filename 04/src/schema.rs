@@ -0,0 +1,441 @@
+//! Declarative rules for the fields of a document.
+//!
+//! A schema can be built in code or read from TOML; see `passport.toml` for
+//! the format and the rules of the puzzle, which are the default schema.
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+use thiserror::Error;
+use toml::Value;
+
+const DEFAULT_SCHEMA: &str = include_str!("../passport.toml");
+
+#[derive(Error, Debug)]
+pub enum SchemaError {
+    #[error("invalid schema: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("field '{0}' must be a table")]
+    NotATable(String),
+    #[error("field '{field}': invalid '{key}': {message}")]
+    InvalidRule {
+        field: String,
+        key: String,
+        message: String,
+    },
+}
+
+/// A condition a field's value has to meet.
+#[derive(Debug, Clone)]
+pub enum Rule {
+    /// an integer in the range
+    Range(RangeInclusive<i64>),
+    /// an integer followed by one of the units, in that unit's range
+    Units(Vec<(String, RangeInclusive<i64>)>),
+    /// matches the regular expression
+    Pattern(Regex),
+    /// only consists of these characters
+    Charset(String),
+    /// one of the listed values
+    OneOf(Vec<String>),
+    /// number of characters
+    Length(RangeInclusive<usize>),
+}
+
+/// An integer written as plain digits, optionally after a `-`. Unlike
+/// `str::parse`, this refuses a leading `+`.
+fn integer(text: &str) -> Option<i64> {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    text.parse().ok()
+}
+
+impl Rule {
+    pub fn check(&self, value: &str) -> bool {
+        match self {
+            Rule::Range(range) => integer(value).is_some_and(|n| range.contains(&n)),
+            Rule::Units(units) => units.iter().any(|(unit, range)| {
+                value
+                    .strip_suffix(unit.as_str())
+                    .and_then(integer)
+                    .is_some_and(|n| range.contains(&n))
+            }),
+            Rule::Pattern(regex) => regex.is_match(value),
+            Rule::Charset(chars) => value.chars().all(|c| chars.contains(c)),
+            Rule::OneOf(values) => values.iter().any(|v| v == value),
+            Rule::Length(range) => range.contains(&value.chars().count()),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn span<T: fmt::Display + PartialEq>(range: &RangeInclusive<T>) -> String {
+            if range.start() == range.end() {
+                range.start().to_string()
+            } else {
+                format!("{}-{}", range.start(), range.end())
+            }
+        }
+
+        match self {
+            Rule::Range(range) => write!(f, "range {}", span(range)),
+            Rule::Units(units) => {
+                let units: Vec<String> = units
+                    .iter()
+                    .map(|(unit, range)| format!("{} {}", span(range), unit))
+                    .collect();
+                write!(f, "units {}", units.join(" or "))
+            }
+            Rule::Pattern(regex) => write!(f, "pattern {}", regex),
+            Rule::Charset(chars) => write!(f, "charset {}", chars),
+            Rule::OneOf(values) => write!(f, "one of {}", values.join(", ")),
+            Rule::Length(range) => write!(f, "length {}", span(range)),
+        }
+    }
+}
+
+/// Why a document isn't valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// a required field is missing
+    Missing { field: String },
+    /// a field's value breaks one of its rules
+    Broken {
+        field: String,
+        value: String,
+        rule: String,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Missing { field } => write!(f, "{}: missing", field),
+            Violation::Broken { field, value, rule } => {
+                write!(f, "{}: '{}' breaks {}", field, value, rule)
+            }
+        }
+    }
+}
+
+/// A field of a document and the rules for its value.
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub name: String,
+    pub required: bool,
+    pub rules: Vec<Rule>,
+}
+
+impl Field {
+    pub fn required(name: &str) -> Field {
+        Field {
+            name: name.to_owned(),
+            required: true,
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn optional(name: &str) -> Field {
+        Field {
+            required: false,
+            ..Field::required(name)
+        }
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Field {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn check(&self, value: &str) -> bool {
+        self.rules.iter().all(|rule| rule.check(value))
+    }
+}
+
+/// The fields a document may or must have.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    fields: Vec<Field>,
+}
+
+fn range<T: TryFrom<i64>>(value: &Value) -> Option<RangeInclusive<T>> {
+    let bound = |v: &Value| v.as_integer().and_then(|n| T::try_from(n).ok());
+    match value.as_array()?.as_slice() {
+        [min, max] => Some(bound(min)?..=bound(max)?),
+        _ => None,
+    }
+}
+
+fn strings(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|v| v.as_str().map(str::to_owned))
+        .collect()
+}
+
+fn parse_rule(key: &str, value: &Value) -> Result<Rule, String> {
+    let expected = |what: &str| format!("expected {}", what);
+
+    match key {
+        "range" => range(value)
+            .map(Rule::Range)
+            .ok_or_else(|| expected("[MIN, MAX]")),
+        "units" => value
+            .as_table()
+            .and_then(|units| {
+                units
+                    .iter()
+                    .map(|(unit, r)| Some((unit.clone(), range(r)?)))
+                    .collect::<Option<_>>()
+            })
+            .map(Rule::Units)
+            .ok_or_else(|| expected("{ UNIT = [MIN, MAX] }")),
+        "pattern" => {
+            let pattern = value.as_str().ok_or_else(|| expected("a string"))?;
+            Regex::new(pattern)
+                .map(Rule::Pattern)
+                .map_err(|why| why.to_string())
+        }
+        "charset" => value
+            .as_str()
+            .map(|chars| Rule::Charset(chars.to_owned()))
+            .ok_or_else(|| expected("a string")),
+        "one_of" => strings(value)
+            .map(Rule::OneOf)
+            .ok_or_else(|| expected("a list of strings")),
+        "length" => match value {
+            Value::Integer(n) if *n >= 0 => Ok(Rule::Length(*n as usize..=*n as usize)),
+            _ => range(value)
+                .map(Rule::Length)
+                .ok_or_else(|| expected("N or [MIN, MAX]")),
+        },
+        _ => Err("unknown rule".to_owned()),
+    }
+}
+
+impl Schema {
+    pub fn new() -> Schema {
+        Schema::default()
+    }
+
+    /// The rules of part II, read from `passport.toml` only the first time.
+    pub fn passport() -> &'static Schema {
+        static PASSPORT: OnceLock<Schema> = OnceLock::new();
+        PASSPORT
+            .get_or_init(|| Schema::from_toml(DEFAULT_SCHEMA).expect("the default schema is valid"))
+    }
+
+    /// Read a schema where every table is a field; see `passport.toml`. The
+    /// fields end up in alphabetical order, since TOML tables don't keep
+    /// theirs.
+    pub fn from_toml(text: &str) -> Result<Schema, SchemaError> {
+        let table = match text.parse::<Value>()? {
+            Value::Table(table) => table,
+            _ => unreachable!("a TOML document is a table"),
+        };
+
+        let mut schema = Schema::new();
+        for (name, spec) in &table {
+            let spec = spec
+                .as_table()
+                .ok_or_else(|| SchemaError::NotATable(name.clone()))?;
+            let invalid = |key: &str, message: String| SchemaError::InvalidRule {
+                field: name.clone(),
+                key: key.to_owned(),
+                message,
+            };
+
+            let mut field = Field::required(name);
+            for (key, value) in spec {
+                if key == "required" {
+                    field.required = value
+                        .as_bool()
+                        .ok_or_else(|| invalid(key, "expected true or false".to_owned()))?;
+                } else {
+                    field = field.rule(parse_rule(key, value).map_err(|why| invalid(key, why))?);
+                }
+            }
+            schema = schema.field(field);
+        }
+
+        Ok(schema)
+    }
+
+    pub fn field(mut self, field: Field) -> Schema {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Whether the document has all required fields.
+    pub fn has_required_fields(&self, document: &HashMap<String, String>) -> bool {
+        self.fields
+            .iter()
+            .filter(|field| field.required)
+            .all(|field| document.contains_key(&field.name))
+    }
+
+    /// Whether `value` is valid for the field `name`; fields that aren't in
+    /// the schema may have any value.
+    pub fn check_field(&self, name: &str, value: &str) -> bool {
+        self.fields
+            .iter()
+            .filter(|field| field.name == name)
+            .all(|field| field.check(value))
+    }
+
+    /// Every missing required field, in the order the schema lists its
+    /// fields (alphabetical for schemas read from TOML), and every rule
+    /// broken by a field, ordered by field name.
+    pub fn violations(&self, document: &HashMap<String, String>) -> Vec<Violation> {
+        let missing = self
+            .fields
+            .iter()
+            .filter(|field| field.required && !document.contains_key(&field.name))
+            .map(|field| Violation::Missing {
+                field: field.name.clone(),
+            });
+
+        let mut present: Vec<(&String, &String)> = document.iter().collect();
+        present.sort();
+        let broken = present.into_iter().flat_map(|(name, value)| {
+            self.fields
+                .iter()
+                .filter(move |field| &field.name == name)
+                .flat_map(|field| &field.rules)
+                .filter(move |rule| !rule.check(value))
+                .map(move |rule| Violation::Broken {
+                    field: name.clone(),
+                    value: value.clone(),
+                    rule: rule.to_string(),
+                })
+        });
+
+        missing.chain(broken).collect()
+    }
+
+    /// Whether the document has all required fields and all of its fields
+    /// follow their rules.
+    pub fn is_valid(&self, document: &HashMap<String, String>) -> bool {
+        self.has_required_fields(document)
+            && document
+                .iter()
+                .all(|(name, value)| self.check_field(name, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_default_rules() {
+        let schema = Schema::passport();
+        assert!(schema.check_field("byr", "2002"));
+        assert!(!schema.check_field("byr", "2003"));
+        assert!(!schema.check_field("byr", "02002"));
+        assert!(schema.check_field("hgt", "60in"));
+        assert!(schema.check_field("hgt", "190cm"));
+        assert!(!schema.check_field("hgt", "190in"));
+        assert!(!schema.check_field("hgt", "190"));
+        assert!(!schema.check_field("hgt", "+190cm"));
+        assert!(schema.check_field("hcl", "#123abc"));
+        assert!(!schema.check_field("hcl", "#123abz"));
+        assert!(!schema.check_field("hcl", "123abc"));
+        assert!(schema.check_field("ecl", "brn"));
+        assert!(!schema.check_field("ecl", "wat"));
+        assert!(schema.check_field("pid", "000000001"));
+        assert!(!schema.check_field("pid", "0123456789"));
+        assert!(schema.check_field("cid", "anything"));
+        assert!(schema.check_field("xyz", "anything"));
+    }
+
+    #[test]
+    fn lists_violations() {
+        let schema = Schema::passport();
+        let document: HashMap<String, String> = [("byr", "1900"), ("hgt", "170"), ("cid", "1")]
+            .iter()
+            .map(|&(k, v)| (k.to_owned(), v.to_owned()))
+            .collect();
+
+        let violations: Vec<String> = schema
+            .violations(&document)
+            .iter()
+            .map(Violation::to_string)
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                "ecl: missing",
+                "eyr: missing",
+                "hcl: missing",
+                "iyr: missing",
+                "pid: missing",
+                "byr: '1900' breaks range 1920-2002",
+                "hgt: '170' breaks units 150-193 cm or 59-76 in",
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_schemas_in_code() {
+        let schema = Schema::new()
+            .field(Field::required("age").rule(Rule::Range(0..=150)))
+            .field(Field::optional("nick").rule(Rule::Length(1..=8)));
+
+        let document = |fields: &[(&str, &str)]| -> HashMap<String, String> {
+            fields
+                .iter()
+                .map(|&(k, v)| (k.to_owned(), v.to_owned()))
+                .collect()
+        };
+        assert!(schema.is_valid(&document(&[("age", "42")])));
+        assert!(schema.is_valid(&document(&[("age", "42"), ("nick", "bob")])));
+        assert!(!schema.is_valid(&document(&[("age", "420")])));
+        assert!(!schema.is_valid(&document(&[("age", "+42")])));
+        assert!(!schema.is_valid(&document(&[("age", "4 2")])));
+        assert!(!schema.is_valid(&document(&[("nick", "bob")])));
+    }
+
+    #[test]
+    fn rejects_invalid_schemas() {
+        assert!(matches!(
+            Schema::from_toml("byr = 3"),
+            Err(SchemaError::NotATable(_))
+        ));
+        assert!(matches!(
+            Schema::from_toml("[byr]\nrange = [1]"),
+            Err(SchemaError::InvalidRule { .. })
+        ));
+        assert!(matches!(
+            Schema::from_toml("[byr]\ncolour = \"red\""),
+            Err(SchemaError::InvalidRule { .. })
+        ));
+        assert!(matches!(
+            Schema::from_toml("[hcl]\npattern = \"[\""),
+            Err(SchemaError::InvalidRule { .. })
+        ));
+    }
+
+    #[test]
+    fn ranges_take_only_digits() {
+        let rule = Rule::Range(1920..=2002);
+        assert!(rule.check("1990"));
+        assert!(!rule.check("+1990"));
+        assert!(!rule.check(" 1990"));
+        assert!(!rule.check(""));
+        assert!(Rule::Range(-5..=5).check("-3"));
+        assert!(!Rule::Range(-5..=5).check("-"));
+    }
+}
@@ -0,0 +1,336 @@
+//! Boarding passes, which encode a seat in binary space partitioning.
+//!
+//! Each letter halves the remaining rows (or columns): the first letter of
+//! a pair keeps the lower half, the second one the upper half. So a pass is
+//! just the row and column written in binary with letters instead of 0 and
+//! 1.
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PassError {
+    #[error("expected {expected} letters, found {found}")]
+    WrongLength { expected: usize, found: usize },
+    /// `position` starts at 1
+    #[error("invalid letter '{found}' at position {position}, expected '{low}' or '{high}'")]
+    InvalidLetter {
+        position: usize,
+        found: char,
+        low: char,
+        high: char,
+    },
+    #[error("seat {row}/{column} isn't on the plane")]
+    NoSuchSeat { row: u32, column: u32 },
+    #[error("invalid layout: {0}")]
+    InvalidLayout(String),
+}
+
+/// How many rows and columns a plane has, and how passes write them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaneLayout {
+    row_bits: u32,
+    column_bits: u32,
+    /// letters for the lower and upper half of the rows
+    row_letters: (char, char),
+    /// letters for the lower and upper half of the columns
+    column_letters: (char, char),
+}
+
+impl Default for PlaneLayout {
+    /// 128 rows of 8 seats, as in the puzzle.
+    fn default() -> PlaneLayout {
+        PlaneLayout {
+            row_bits: 7,
+            column_bits: 3,
+            row_letters: ('F', 'B'),
+            column_letters: ('L', 'R'),
+        }
+    }
+}
+
+impl PlaneLayout {
+    /// A layout with at most 31 bits of rows and columns together, so that
+    /// every seat ID fits into a `u32`, and different letters for the lower
+    /// and upper halves.
+    pub fn new(
+        row_bits: u32,
+        column_bits: u32,
+        row_letters: (char, char),
+        column_letters: (char, char),
+    ) -> Result<PlaneLayout, PassError> {
+        if row_bits
+            .checked_add(column_bits)
+            .is_none_or(|bits| bits > 31)
+        {
+            return Err(PassError::InvalidLayout(
+                "at most 31 bits of rows and columns".to_owned(),
+            ));
+        }
+        if row_letters.0 == row_letters.1 || column_letters.0 == column_letters.1 {
+            return Err(PassError::InvalidLayout(
+                "the letters for lower and upper half must differ".to_owned(),
+            ));
+        }
+
+        Ok(PlaneLayout {
+            row_bits,
+            column_bits,
+            row_letters,
+            column_letters,
+        })
+    }
+
+    pub fn rows(&self) -> u32 {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> u32 {
+        1 << self.column_bits
+    }
+
+    pub fn seats(&self) -> u32 {
+        self.rows() * self.columns()
+    }
+}
+
+impl fmt::Display for PlaneLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{}{},{}{}",
+            self.row_bits,
+            self.column_bits,
+            self.row_letters.0,
+            self.row_letters.1,
+            self.column_letters.0,
+            self.column_letters.1
+        )
+    }
+}
+
+impl FromStr for PlaneLayout {
+    type Err = PassError;
+
+    /// Parse `ROW_BITS,COLUMN_BITS,ROW_LETTERS,COLUMN_LETTERS`, e.g. the
+    /// default `7,3,FB,LR`.
+    fn from_str(s: &str) -> Result<PlaneLayout, PassError> {
+        let invalid = || PassError::InvalidLayout(format!("'{}', expected e.g. 7,3,FB,LR", s));
+        let letters = |pair: &str| {
+            let mut chars = pair.chars();
+            match (chars.next(), chars.next(), chars.next()) {
+                (Some(low), Some(high), None) => Ok((low, high)),
+                _ => Err(invalid()),
+            }
+        };
+
+        match s.split(',').collect::<Vec<_>>()[..] {
+            [row_bits, column_bits, row_letters, column_letters] => PlaneLayout::new(
+                row_bits.parse().map_err(|_| invalid())?,
+                column_bits.parse().map_err(|_| invalid())?,
+                letters(row_letters)?,
+                letters(column_letters)?,
+            ),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardingPass {
+    row: u32,
+    column: u32,
+    layout: PlaneLayout,
+}
+
+/// `bits` letters from `s` as a binary number.
+fn decode(s: &str, offset: usize, bits: u32, (low, high): (char, char)) -> Result<u32, PassError> {
+    s.chars()
+        .skip(offset)
+        .take(bits as usize)
+        .enumerate()
+        .try_fold(0, |n, (i, c)| match c {
+            c if c == low => Ok(n << 1),
+            c if c == high => Ok(n << 1 | 1),
+            found => Err(PassError::InvalidLetter {
+                position: offset + i + 1,
+                found,
+                low,
+                high,
+            }),
+        })
+}
+
+fn encode(n: u32, bits: u32, (low, high): (char, char)) -> String {
+    (0..bits)
+        .rev()
+        .map(|bit| if n >> bit & 1 == 0 { low } else { high })
+        .collect()
+}
+
+impl BoardingPass {
+    pub fn new(row: u32, column: u32, layout: PlaneLayout) -> Result<BoardingPass, PassError> {
+        if row >= layout.rows() || column >= layout.columns() {
+            return Err(PassError::NoSuchSeat { row, column });
+        }
+
+        Ok(BoardingPass {
+            row,
+            column,
+            layout,
+        })
+    }
+
+    pub fn from_seat_id(seat_id: u32, layout: PlaneLayout) -> Result<BoardingPass, PassError> {
+        BoardingPass::new(
+            seat_id / layout.columns(),
+            seat_id % layout.columns(),
+            layout,
+        )
+    }
+
+    /// Decode a pass with exactly the letters of the layout.
+    pub fn parse(s: &str, layout: PlaneLayout) -> Result<BoardingPass, PassError> {
+        let length = (layout.row_bits + layout.column_bits) as usize;
+        let found = s.chars().count();
+        if found != length {
+            return Err(PassError::WrongLength {
+                expected: length,
+                found,
+            });
+        }
+
+        Ok(BoardingPass {
+            row: decode(s, 0, layout.row_bits, layout.row_letters)?,
+            column: decode(
+                s,
+                layout.row_bits as usize,
+                layout.column_bits,
+                layout.column_letters,
+            )?,
+            layout,
+        })
+    }
+
+    pub fn row(&self) -> u32 {
+        self.row
+    }
+
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    pub fn seat_id(&self) -> u32 {
+        self.row * self.layout.columns() + self.column
+    }
+
+    pub fn layout(&self) -> PlaneLayout {
+        self.layout
+    }
+}
+
+impl FromStr for BoardingPass {
+    type Err = PassError;
+
+    /// Decode a pass for the default layout.
+    fn from_str(s: &str) -> Result<BoardingPass, PassError> {
+        BoardingPass::parse(s, PlaneLayout::default())
+    }
+}
+
+impl fmt::Display for BoardingPass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            encode(self.row, self.layout.row_bits, self.layout.row_letters),
+            encode(
+                self.column,
+                self.layout.column_bits,
+                self.layout.column_letters
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_examples() {
+        let examples = [
+            ("FBFBBFFRLR", 44, 5, 357),
+            ("BFFFBBFRRR", 70, 7, 567),
+            ("FFFBBBFRRR", 14, 7, 119),
+            ("BBFFBBFRLL", 102, 4, 820),
+        ];
+
+        for &(pass, row, column, seat_id) in &examples {
+            let decoded: BoardingPass = pass.parse().unwrap();
+            assert_eq!(
+                (decoded.row(), decoded.column(), decoded.seat_id()),
+                (row, column, seat_id)
+            );
+            assert_eq!(decoded.to_string(), pass);
+        }
+    }
+
+    #[test]
+    fn round_trips_other_layouts() {
+        let layout: PlaneLayout = "4,2,DU,<>".parse().unwrap();
+        assert_eq!(layout.seats(), 64);
+
+        for seat_id in 0..layout.seats() {
+            let pass = BoardingPass::from_seat_id(seat_id, layout).unwrap();
+            let encoded = pass.to_string();
+            assert_eq!(encoded.len(), 6);
+            assert_eq!(BoardingPass::parse(&encoded, layout), Ok(pass));
+        }
+        assert_eq!(BoardingPass::parse("UDUD><", layout).unwrap().seat_id(), 42);
+    }
+
+    #[test]
+    fn rejects_invalid_passes() {
+        assert_eq!(
+            "FBFBBFFRL".parse::<BoardingPass>(),
+            Err(PassError::WrongLength {
+                expected: 10,
+                found: 9
+            })
+        );
+        assert_eq!(
+            "FBFBBFFRXR".parse::<BoardingPass>(),
+            Err(PassError::InvalidLetter {
+                position: 9,
+                found: 'X',
+                low: 'L',
+                high: 'R'
+            })
+        );
+        // column letters in the row part
+        assert!(matches!(
+            "FBFBBFRRLR".parse::<BoardingPass>(),
+            Err(PassError::InvalidLetter { position: 7, .. })
+        ));
+        assert_eq!(
+            BoardingPass::new(128, 0, PlaneLayout::default()),
+            Err(PassError::NoSuchSeat {
+                row: 128,
+                column: 0
+            })
+        );
+        assert!("7,3,FF,LR".parse::<PlaneLayout>().is_err());
+        assert!("20,20,FB,LR".parse::<PlaneLayout>().is_err());
+        assert!("32,0,FB,LR".parse::<PlaneLayout>().is_err());
+        assert!("4294967295,1,FB,LR".parse::<PlaneLayout>().is_err());
+        assert_eq!(
+            PlaneLayout::new(30, 1, ('F', 'B'), ('L', 'R'))
+                .unwrap()
+                .seats(),
+            1 << 31
+        );
+        assert!("7,3,FB".parse::<PlaneLayout>().is_err());
+    }
+}
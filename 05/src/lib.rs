@@ -0,0 +1,98 @@
+use aoc_common::{Answer, Error, Solution};
+use boarding_pass::{BoardingPass, PlaneLayout};
+use seat_map::SeatMap;
+
+pub mod boarding_pass;
+pub mod seat_map;
+
+/// The boarding passes of a plane.
+pub struct Flight {
+    pub layout: PlaneLayout,
+    pub passes: Vec<BoardingPass>,
+}
+
+impl Flight {
+    /// Decode one pass per line.
+    pub fn parse(input: &str, layout: PlaneLayout) -> Result<Flight, Error> {
+        let passes = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                BoardingPass::parse(line, layout).map_err(|why| Error::parse(i + 1, why))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Flight { layout, passes })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Flight;
+
+    fn parse(input: &str) -> Result<Flight, Error> {
+        Flight::parse(input, PlaneLayout::default())
+    }
+
+    fn part1(flight: &Flight) -> Result<Answer, Error> {
+        flight
+            .passes
+            .iter()
+            .map(BoardingPass::seat_id)
+            .max()
+            .map(|highest_id| highest_id.into())
+            .ok_or_else(|| Error::InvalidInput("no boarding passes".to_owned()))
+    }
+
+    /// The only free seat with booked seats on both sides.
+    fn part2(flight: &Flight) -> Result<Answer, Error> {
+        match SeatMap::new(flight).free_runs(1)[..] {
+            [ref seat] => Ok(seat.start.into()),
+            [] => Err(Error::NoSolution("found no free seat".to_owned())),
+            _ => Err(Error::NoSolution(
+                "found more than one free seat".to_owned(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    #[test]
+    fn rejects_invalid_passes() {
+        assert!(matches!(
+            Day05::parse("FBFBBFFRLR\nFBFBBFFRLX"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day05>("BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL", Part::One).unwrap(),
+            Answer::Unsigned(820)
+        );
+    }
+
+    #[test]
+    fn finds_free_seat() {
+        // seats 8 to 15 (row 1), except 11
+        let passes = "FFFFFFBLLL
+FFFFFFBLLR
+FFFFFFBLRL
+FFFFFFBRLL
+FFFFFFBRLR
+FFFFFFBRRL
+FFFFFFBRRR";
+        assert_eq!(
+            solve_part::<Day05>(passes, Part::Two).unwrap(),
+            Answer::Unsigned(11)
+        );
+    }
+}
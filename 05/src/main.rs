@@ -0,0 +1,190 @@
+//! ```text
+//! aoc-2020-05 [--format text|json] [-v|-vv]
+//! aoc-2020-05 decode [PASS...] [--layout LAYOUT] [--input PATH]
+//! aoc-2020-05 encode SEAT_ID... [--layout LAYOUT]
+//! aoc-2020-05 map [--layout LAYOUT] [--input PATH]
+//! aoc-2020-05 free [--run N] [--layout LAYOUT] [--input PATH]
+//! aoc-2020-05 occupancy [--layout LAYOUT] [--input PATH]
+//! ```
+//!
+//! `decode` prints row, column and seat ID of the given passes, or of all
+//! passes in the input. `encode` prints the pass for each seat ID. The
+//! layout is `ROW_BITS,COLUMN_BITS,ROW_LETTERS,COLUMN_LETTERS` and defaults
+//! to the puzzle's `7,3,FB,LR`.
+//!
+//! `map` draws the plane row by row with booked (`#`), free (`.`) and
+//! missing (blank) seats. `free` lists the free seats, or with `--run` the
+//! runs of exactly N free seats between booked ones. `occupancy` prints how
+//! many seats of each row are booked, free and missing.
+
+use aoc_2020_05::boarding_pass::{BoardingPass, PlaneLayout};
+use aoc_2020_05::seat_map::SeatMap;
+use aoc_2020_05::{Day05, Flight};
+use aoc_common::{Error, Solution};
+use std::env;
+use std::path::PathBuf;
+
+struct Options {
+    input: PathBuf,
+    layout: PlaneLayout,
+    run: Option<u32>,
+    values: Vec<String>,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, Error> {
+    let mut options = Options {
+        input: PathBuf::from("input.txt"),
+        layout: PlaneLayout::default(),
+        run: None,
+        values: Vec::new(),
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::InvalidArgument(format!("{} needs a value", arg)))
+        };
+
+        match arg.as_str() {
+            "--input" => options.input = PathBuf::from(value()?),
+            "--layout" => {
+                options.layout = value()?
+                    .parse()
+                    .map_err(|why| Error::InvalidArgument(format!("--layout: {}", why)))?
+            }
+            "--run" => {
+                options.run = Some(
+                    value()?
+                        .parse()
+                        .map_err(|why| Error::InvalidArgument(format!("--run: {}", why)))?,
+                )
+            }
+            other => options.values.push(other.to_owned()),
+        }
+    }
+
+    Ok(options)
+}
+
+fn describe(pass: &BoardingPass) -> String {
+    format!(
+        "{}: row {}, column {}, seat ID {}\n",
+        pass,
+        pass.row(),
+        pass.column(),
+        pass.seat_id()
+    )
+}
+
+fn decode(options: &Options) -> Result<String, Error> {
+    let passes = if options.values.is_empty() {
+        let input = aoc_common::read_input(&options.input)?;
+        Flight::parse(&input, options.layout)?.passes
+    } else {
+        options
+            .values
+            .iter()
+            .map(|pass| {
+                BoardingPass::parse(pass, options.layout)
+                    .map_err(|why| Error::InvalidArgument(format!("{}: {}", pass, why)))
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    Ok(passes.iter().map(describe).collect())
+}
+
+fn encode(options: &Options) -> Result<String, Error> {
+    if options.values.is_empty() {
+        return Err(Error::InvalidArgument(
+            "encode needs at least one seat ID".to_owned(),
+        ));
+    }
+
+    let mut output = String::new();
+    for seat_id in &options.values {
+        let pass = seat_id
+            .parse()
+            .map_err(|why| format!("{}", why))
+            .and_then(|seat_id| {
+                BoardingPass::from_seat_id(seat_id, options.layout).map_err(|why| why.to_string())
+            })
+            .map_err(|why| Error::InvalidArgument(format!("{}: {}", seat_id, why)))?;
+        output.push_str(&describe(&pass));
+    }
+
+    Ok(output)
+}
+
+fn load_map(options: &Options) -> Result<SeatMap, Error> {
+    if let Some(value) = options.values.first() {
+        return Err(Error::InvalidArgument(format!(
+            "unexpected argument '{}'",
+            value
+        )));
+    }
+
+    let input = aoc_common::read_input(&options.input)?;
+    Ok(SeatMap::new(&Flight::parse(&input, options.layout)?))
+}
+
+fn map(options: &Options) -> Result<String, Error> {
+    Ok(load_map(options)?.render())
+}
+
+fn free(options: &Options) -> Result<String, Error> {
+    let map = load_map(options)?;
+    let layout = map.layout();
+
+    match options.run {
+        None => Ok(map.free_seats().iter().map(describe).collect()),
+        Some(length) => {
+            let mut output = String::new();
+            for run in map.free_runs(length) {
+                let first = BoardingPass::from_seat_id(run.start, layout);
+                let last = BoardingPass::from_seat_id(run.end - 1, layout);
+                if let (Ok(first), Ok(last)) = (first, last) {
+                    output.push_str(&format!(
+                        "seat IDs {} to {}: {} to {}\n",
+                        run.start,
+                        run.end - 1,
+                        first,
+                        last
+                    ));
+                }
+            }
+            Ok(output)
+        }
+    }
+}
+
+fn occupancy(options: &Options) -> Result<String, Error> {
+    let mut output = String::from("row\tbooked\tfree\tmissing\n");
+    for (row, occupancy) in load_map(options)?.occupancy().iter().enumerate() {
+        output.push_str(&format!(
+            "{}\t{}\t{}\t{}\n",
+            row, occupancy.booked, occupancy.free, occupancy.missing
+        ));
+    }
+
+    Ok(output)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command: fn(&Options) -> Result<String, Error> = match args.first().map(String::as_str) {
+        Some("decode") => decode,
+        Some("encode") => encode,
+        Some("map") => map,
+        Some("free") => free,
+        Some("occupancy") => occupancy,
+        _ => return aoc_common::run::<Day05>(),
+    };
+
+    match parse_options(&args[1..]).and_then(|options| command(&options)) {
+        Ok(output) => print!("{}", output),
+        Err(why) => aoc_common::fail(Day05::DAY, why),
+    }
+}
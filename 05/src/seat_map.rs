@@ -0,0 +1,230 @@
+//! Which seats of the plane are booked, and which of the others exist.
+//!
+//! The puzzle says that some seats at the very front and back of the plane
+//! don't exist. Since nobody can book them, every seat before the first
+//! booked one and after the last booked one is counted as missing; unlisted
+//! seats in between are free.
+
+use crate::boarding_pass::{BoardingPass, PlaneLayout};
+use crate::Flight;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seat {
+    Booked,
+    Free,
+    /// in the front or back block
+    Missing,
+}
+
+impl Seat {
+    fn symbol(self) -> char {
+        match self {
+            Seat::Booked => '#',
+            Seat::Free => '.',
+            Seat::Missing => ' ',
+        }
+    }
+}
+
+/// Number of seats in a row by state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Occupancy {
+    pub booked: u32,
+    pub free: u32,
+    pub missing: u32,
+}
+
+#[derive(Debug)]
+pub struct SeatMap {
+    layout: PlaneLayout,
+    /// seat IDs of the booked seats; the plane itself can be far too big to
+    /// keep every seat
+    booked: BTreeSet<u32>,
+}
+
+impl SeatMap {
+    pub fn new(flight: &Flight) -> SeatMap {
+        SeatMap {
+            layout: flight.layout,
+            booked: flight.passes.iter().map(BoardingPass::seat_id).collect(),
+        }
+    }
+
+    pub fn layout(&self) -> PlaneLayout {
+        self.layout
+    }
+
+    /// The first and last booked seat IDs, unless nothing is booked.
+    fn bounds(&self) -> Option<(u32, u32)> {
+        Some((
+            *self.booked.iter().next()?,
+            *self.booked.iter().next_back()?,
+        ))
+    }
+
+    fn seat(&self, seat_id: u32) -> Seat {
+        if self.booked.contains(&seat_id) {
+            Seat::Booked
+        } else if self
+            .bounds()
+            .is_some_and(|(first, last)| first < seat_id && seat_id < last)
+        {
+            Seat::Free
+        } else {
+            Seat::Missing
+        }
+    }
+
+    /// The seat at `row` and `column`, or `None` if the plane has no such
+    /// seat.
+    pub fn get(&self, row: u32, column: u32) -> Option<Seat> {
+        BoardingPass::new(row, column, self.layout)
+            .ok()
+            .map(|pass| self.seat(pass.seat_id()))
+    }
+
+    fn pass(&self, seat_id: u32) -> BoardingPass {
+        BoardingPass::from_seat_id(seat_id, self.layout).expect("seat IDs are on the plane")
+    }
+
+    /// The free seats between each two booked seats that aren't next to
+    /// each other, as ranges of seat IDs.
+    fn gaps(&self) -> impl Iterator<Item = Range<u32>> + '_ {
+        self.booked
+            .iter()
+            .zip(self.booked.iter().skip(1))
+            .map(|(&before, &after)| before + 1..after)
+            .filter(|gap| !gap.is_empty())
+    }
+
+    /// All free seats, i.e. neither booked nor in the front or back block.
+    pub fn free_seats(&self) -> Vec<BoardingPass> {
+        self.gaps().flatten().map(|id| self.pass(id)).collect()
+    }
+
+    /// Seat IDs of all runs of exactly `length` free seats in a row, with a
+    /// booked seat before and after each. Runs go on across the end of a
+    /// row, like the seat IDs do.
+    pub fn free_runs(&self, length: u32) -> Vec<Range<u32>> {
+        self.gaps()
+            .filter(|gap| gap.len() as u32 == length)
+            .collect()
+    }
+
+    /// How many seats of each row are booked, free and missing, from the
+    /// front row to the back.
+    pub fn occupancy(&self) -> Vec<Occupancy> {
+        let columns = self.layout.columns();
+        let bounds = self.bounds();
+
+        (0..self.layout.rows())
+            .map(|row| {
+                let start = row * columns;
+                let end = start + columns - 1;
+                let booked = self.booked.range(start..=end).count() as u32;
+                // seats of the row from the first booked seat to the last
+                let between = match bounds {
+                    Some((first, last)) if first <= end && start <= last => {
+                        last.min(end) - first.max(start) + 1
+                    }
+                    _ => 0,
+                };
+
+                Occupancy {
+                    booked,
+                    free: between - booked,
+                    missing: columns - between,
+                }
+            })
+            .collect()
+    }
+
+    /// One line per row with its number and seats from left to right:
+    /// `#` is booked, `.` is free and missing seats are blank.
+    pub fn render(&self) -> String {
+        let width = (self.layout.rows() - 1).to_string().len();
+        let mut rendered = String::new();
+
+        let columns = self.layout.columns();
+        for row in 0..self.layout.rows() {
+            let seats: String = (row * columns..(row + 1) * columns)
+                .map(|id| self.seat(id).symbol())
+                .collect();
+            writeln!(rendered, "{:>width$} |{}|", row, seats, width = width).unwrap();
+        }
+
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 4 rows of 4 seats; seats 5 to 12 except 7, 9 and 10 are booked
+    fn example() -> SeatMap {
+        let layout = "2,2,FB,LR".parse().unwrap();
+        let passes = [5, 6, 8, 11, 12]
+            .iter()
+            .map(|&id| BoardingPass::from_seat_id(id, layout).unwrap())
+            .collect();
+        SeatMap::new(&Flight { layout, passes })
+    }
+
+    #[test]
+    fn tells_free_from_missing_seats() {
+        let map = example();
+        assert_eq!(map.get(0, 0), Some(Seat::Missing));
+        assert_eq!(map.get(1, 1), Some(Seat::Booked));
+        assert_eq!(map.get(1, 3), Some(Seat::Free));
+        assert_eq!(map.get(3, 3), Some(Seat::Missing));
+        assert_eq!(map.get(4, 0), None);
+
+        let free: Vec<u32> = map.free_seats().iter().map(BoardingPass::seat_id).collect();
+        assert_eq!(free, vec![7, 9, 10]);
+        assert_eq!(map.free_runs(1), vec![7..8]);
+        assert_eq!(map.free_runs(2), vec![9..11]);
+        assert_eq!(map.free_runs(3), vec![]);
+    }
+
+    #[test]
+    fn handles_huge_planes() {
+        let layout = "16,15,FB,LR".parse().unwrap();
+        let passes = [1 << 20, (1 << 20) + 3, (1 << 20) + 4]
+            .iter()
+            .map(|&id| BoardingPass::from_seat_id(id, layout).unwrap())
+            .collect();
+        let map = SeatMap::new(&Flight { layout, passes });
+
+        let free: Vec<u32> = map.free_seats().iter().map(BoardingPass::seat_id).collect();
+        assert_eq!(free, vec![(1 << 20) + 1, (1 << 20) + 2]);
+        assert_eq!(map.free_runs(2), vec![(1 << 20) + 1..(1 << 20) + 3]);
+        assert_eq!(map.get(0, 0), Some(Seat::Missing));
+        assert_eq!(map.get(32, 3), Some(Seat::Booked));
+        assert_eq!(
+            map.occupancy()[32],
+            Occupancy {
+                booked: 3,
+                free: 2,
+                missing: (1 << 15) - 5
+            }
+        );
+    }
+
+    #[test]
+    fn counts_and_renders_rows() {
+        let map = example();
+        assert_eq!(
+            map.occupancy()[1],
+            Occupancy {
+                booked: 2,
+                free: 1,
+                missing: 1
+            }
+        );
+        assert_eq!(map.render(), "0 |    |\n1 | ##.|\n2 |#..#|\n3 |#   |\n");
+    }
+}
@@ -0,0 +1,250 @@
+//! Customs declaration answers as bit sets, one bit per question `a` to `z`.
+
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Sub};
+use std::str::FromStr;
+
+/// The questions somebody answered "yes" to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Answers(u32);
+
+impl Answers {
+    pub const NONE: Answers = Answers(0);
+    pub const ALL: Answers = Answers((1 << 26) - 1);
+
+    fn bit(question: char) -> Option<u32> {
+        if question.is_ascii_lowercase() {
+            Some(1 << (question as u32 - 'a' as u32))
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(self, question: char) -> bool {
+        Answers::bit(question).is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Answers) -> Answers {
+        Answers(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Answers) -> Answers {
+        Answers(self.0 & other.0)
+    }
+
+    /// The questions in `self` but not in `other`.
+    pub fn difference(self, other: Answers) -> Answers {
+        Answers(self.0 & !other.0)
+    }
+
+    /// The questions in alphabetical order.
+    pub fn questions(self) -> impl Iterator<Item = char> {
+        ('a'..='z').filter(move |&question| self.contains(question))
+    }
+}
+
+impl BitOr for Answers {
+    type Output = Answers;
+
+    fn bitor(self, other: Answers) -> Answers {
+        self.union(other)
+    }
+}
+
+impl BitAnd for Answers {
+    type Output = Answers;
+
+    fn bitand(self, other: Answers) -> Answers {
+        self.intersection(other)
+    }
+}
+
+impl Sub for Answers {
+    type Output = Answers;
+
+    fn sub(self, other: Answers) -> Answers {
+        self.difference(other)
+    }
+}
+
+impl FromStr for Answers {
+    /// the first character that isn't a question
+    type Err = char;
+
+    /// Parse the letters of the questions; the same letter may repeat.
+    fn from_str(s: &str) -> Result<Answers, char> {
+        s.chars().try_fold(Answers::NONE, |answers, question| {
+            Answers::bit(question)
+                .map(|bit| Answers(answers.0 | bit))
+                .ok_or(question)
+        })
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.questions()
+            .try_for_each(|question| write!(f, "{}", question))
+    }
+}
+
+/// The answers of each person in a group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub people: Vec<Answers>,
+}
+
+impl Group {
+    /// Questions anyone in the group answered.
+    pub fn anyone(&self) -> Answers {
+        self.people
+            .iter()
+            .fold(Answers::NONE, |all, &person| all | person)
+    }
+
+    /// Questions everyone in the group answered.
+    pub fn everyone(&self) -> Answers {
+        self.people
+            .iter()
+            .fold(Answers::ALL, |all, &person| all & person)
+    }
+
+    /// Questions some, but not all people in the group answered.
+    pub fn disputed(&self) -> Answers {
+        self.anyone() - self.everyone()
+    }
+
+    /// Questions at least `k` people in the group answered. With `k` of 0,
+    /// that's every question.
+    pub fn at_least(&self, k: usize) -> Answers {
+        // bit-sliced counters: `counts[i]` has bit `i` of each question's
+        // count. Counts go up one by one, so every question with at least `k`
+        // answers had a count of exactly `k` at some point.
+        let mut reached = if k == 0 { Answers::ALL } else { Answers::NONE };
+        let mut counts = vec![0u32; usize::BITS as usize - k.leading_zeros() as usize];
+
+        for person in &self.people {
+            let mut carry = person.0;
+            for count in &mut counts {
+                let sum = *count ^ carry;
+                carry &= *count;
+                *count = sum;
+            }
+
+            let equal = counts
+                .iter()
+                .enumerate()
+                .fold(Answers::ALL.0, |equal, (i, count)| {
+                    let expected = if k >> i & 1 == 1 { *count } else { !*count };
+                    equal & expected
+                });
+            reached.0 |= equal & Answers::ALL.0;
+        }
+
+        reached
+    }
+}
+
+/// How often one question was answered across all groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuestionStats {
+    pub question: char,
+    /// people who answered it
+    pub people: usize,
+    /// groups where anyone answered it
+    pub anyone: usize,
+    /// groups where everyone answered it
+    pub everyone: usize,
+}
+
+/// Statistics for each question from `a` to `z`.
+pub fn statistics(groups: &[Group]) -> Vec<QuestionStats> {
+    ('a'..='z')
+        .map(|question| {
+            let count = |answers: &mut dyn Iterator<Item = Answers>| {
+                answers.filter(|answers| answers.contains(question)).count()
+            };
+
+            QuestionStats {
+                question,
+                people: count(&mut groups.iter().flat_map(|group| group.people.iter().copied())),
+                anyone: count(&mut groups.iter().map(Group::anyone)),
+                everyone: count(&mut groups.iter().map(Group::everyone)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(people: &[&str]) -> Group {
+        Group {
+            people: people
+                .iter()
+                .map(|person| person.parse().unwrap())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_and_combines_answers() {
+        let abc: Answers = "abcb".parse().unwrap();
+        let bcd: Answers = "dcb".parse().unwrap();
+        assert_eq!(abc.len(), 3);
+        assert_eq!(abc.to_string(), "abc");
+        assert_eq!((abc | bcd).to_string(), "abcd");
+        assert_eq!((abc & bcd).to_string(), "bc");
+        assert_eq!((abc - bcd).to_string(), "a");
+        assert_eq!("abC".parse::<Answers>(), Err('C'));
+        assert_eq!("".parse::<Answers>(), Ok(Answers::NONE));
+        assert_eq!(Answers::ALL.to_string().len(), 26);
+    }
+
+    #[test]
+    fn finds_quorums() {
+        let group = group(&["abc", "ab", "a", "ad"]);
+        assert_eq!(group.anyone().to_string(), "abcd");
+        assert_eq!(group.everyone().to_string(), "a");
+        assert_eq!(group.disputed().to_string(), "bcd");
+        assert_eq!(group.at_least(0), Answers::ALL);
+        assert_eq!(group.at_least(1), group.anyone());
+        assert_eq!(group.at_least(2).to_string(), "ab");
+        assert_eq!(group.at_least(3).to_string(), "a");
+        assert_eq!(group.at_least(4), group.everyone());
+        assert_eq!(group.at_least(5), Answers::NONE);
+    }
+
+    #[test]
+    fn counts_questions() {
+        let stats = statistics(&[group(&["ab", "a"]), group(&["b"])]);
+        assert_eq!(
+            stats[0],
+            QuestionStats {
+                question: 'a',
+                people: 2,
+                anyone: 1,
+                everyone: 1
+            }
+        );
+        assert_eq!(
+            stats[1],
+            QuestionStats {
+                question: 'b',
+                people: 2,
+                anyone: 2,
+                everyone: 1
+            }
+        );
+        assert_eq!(stats[25].people, 0);
+    }
+}
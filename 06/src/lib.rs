@@ -0,0 +1,100 @@
+use answers::Group;
+use aoc_common::{Answer, Error, Solution};
+
+pub mod answers;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Vec<Group>;
+
+    /// Parse one person per line, with an empty line after each group.
+    fn parse(input: &str) -> Result<Vec<Group>, Error> {
+        let mut groups = Vec::new();
+        let mut people = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                if !people.is_empty() {
+                    groups.push(Group { people });
+                    people = Vec::new();
+                }
+                continue;
+            }
+
+            let answers = line.parse().map_err(|question| {
+                Error::parse(i + 1, format!("unknown question '{}'", question))
+            })?;
+            people.push(answers);
+        }
+        if !people.is_empty() {
+            groups.push(Group { people });
+        }
+
+        Ok(groups)
+    }
+
+    fn part1(groups: &Vec<Group>) -> Result<Answer, Error> {
+        Ok(groups
+            .iter()
+            .map(|group| group.anyone().len())
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(groups: &Vec<Group>) -> Result<Answer, Error> {
+        Ok(groups
+            .iter()
+            .map(|group| group.everyone().len())
+            .sum::<usize>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day06>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(11)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day06>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(6)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_questions() {
+        assert!(matches!(
+            Day06::parse("ab\n\na1"),
+            Err(Error::Parse { line: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+//! ```text
+//! aoc-2020-06 [--format text|json] [-v|-vv]
+//! aoc-2020-06 quorum K [--input PATH]
+//! aoc-2020-06 stats [--input PATH]
+//! ```
+//!
+//! `quorum` sums up, over all groups, the questions at least `K` people of
+//! the group answered "yes" to. `stats` prints for each question how many
+//! people answered it, and in how many groups anyone and everyone did.
+
+use aoc_2020_06::answers;
+use aoc_2020_06::Day06;
+use aoc_common::{Error, Solution};
+use std::env;
+use std::path::PathBuf;
+
+struct Options {
+    input: PathBuf,
+    values: Vec<String>,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, Error> {
+    let mut options = Options {
+        input: PathBuf::from("input.txt"),
+        values: Vec::new(),
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                options.input = PathBuf::from(
+                    args.next()
+                        .ok_or_else(|| Error::InvalidArgument(format!("{} needs a value", arg)))?,
+                )
+            }
+            other => options.values.push(other.to_owned()),
+        }
+    }
+
+    Ok(options)
+}
+
+fn load_groups(options: &Options) -> Result<Vec<answers::Group>, Error> {
+    Day06::parse(&aoc_common::read_input(&options.input)?)
+}
+
+fn quorum(options: &Options) -> Result<String, Error> {
+    let k: usize = match &options.values[..] {
+        [k] => k
+            .parse()
+            .map_err(|why| Error::InvalidArgument(format!("{}: {}", k, why)))?,
+        _ => {
+            return Err(Error::InvalidArgument(
+                "quorum needs exactly one number of people".to_owned(),
+            ))
+        }
+    };
+
+    let total: usize = load_groups(options)?
+        .iter()
+        .map(|group| group.at_least(k).len())
+        .sum();
+
+    Ok(format!("{}\n", total))
+}
+
+fn stats(options: &Options) -> Result<String, Error> {
+    if let Some(value) = options.values.first() {
+        return Err(Error::InvalidArgument(format!(
+            "unexpected argument '{}'",
+            value
+        )));
+    }
+
+    let mut output = String::from("question\tpeople\tanyone\teveryone\n");
+    for stats in answers::statistics(&load_groups(options)?) {
+        output.push_str(&format!(
+            "{}\t{}\t{}\t{}\n",
+            stats.question, stats.people, stats.anyone, stats.everyone
+        ));
+    }
+
+    Ok(output)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command: fn(&Options) -> Result<String, Error> = match args.first().map(String::as_str) {
+        Some("quorum") => quorum,
+        Some("stats") => stats,
+        _ => return aoc_common::run::<Day06>(),
+    };
+
+    match parse_options(&args[1..]).and_then(|options| command(&options)) {
+        Ok(output) => print!("{}", output),
+        Err(why) => aoc_common::fail(Day06::DAY, why),
+    }
+}
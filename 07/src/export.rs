@@ -0,0 +1,205 @@
+//! The rules as Graphviz DOT or JSON, for looking at them with other tools.
+
+use crate::graph::{BagGraph, ColorId};
+use aoc_common::json_string;
+use std::fmt::Write;
+
+/// Which part of the graph to export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subgraph {
+    All,
+    /// a colour and everything it eventually contains
+    From(ColorId),
+    /// a colour and everything that may eventually contain it
+    Into(ColorId),
+}
+
+/// Whether each colour is in the subgraph, by ID.
+fn members(graph: &BagGraph, subgraph: Subgraph) -> Vec<bool> {
+    let (id, reachable) = match subgraph {
+        Subgraph::All => return vec![true; graph.len()],
+        Subgraph::From(id) => (id, graph.descendants(id)),
+        Subgraph::Into(id) => (id, graph.ancestors(id)),
+    };
+
+    let mut members = vec![false; graph.len()];
+    members[id] = true;
+    for id in reachable {
+        members[id] = true;
+    }
+
+    members
+}
+
+/// The edges between colours in the subgraph, by container in rule order.
+/// A colour defined twice may list the same item twice; that's a single
+/// edge with the counts added up.
+fn edges(graph: &BagGraph, subgraph: Subgraph) -> Vec<(ColorId, Vec<(ColorId, u64)>)> {
+    let members = members(graph, subgraph);
+
+    (0..graph.len())
+        .filter(|&id| members[id])
+        .map(|id| {
+            let mut contents: Vec<(ColorId, u64)> = Vec::new();
+            for &(item, count) in graph.contents(id) {
+                if !members[item] {
+                    continue;
+                }
+                match contents.iter_mut().find(|(known, _)| *known == item) {
+                    Some((_, total)) => *total += count,
+                    None => contents.push((item, count)),
+                }
+            }
+            (id, contents)
+        })
+        .collect()
+}
+
+/// `text` as a quoted DOT ID. DOT only knows the escapes `\"` and `\\`, so
+/// everything else is written as is.
+fn dot_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A directed graph with an edge from each bag to the bags it contains,
+/// labelled with how many.
+pub fn to_dot(graph: &BagGraph, subgraph: Subgraph) -> String {
+    let mut dot = String::from("digraph bags {\n");
+
+    for (id, contents) in edges(graph, subgraph) {
+        let container = dot_string(graph.color(id));
+        if contents.is_empty() {
+            writeln!(dot, "    {};", container).unwrap();
+        }
+        for (item, count) in contents {
+            writeln!(
+                dot,
+                "    {} -> {} [label={}];",
+                container,
+                dot_string(graph.color(item)),
+                count
+            )
+            .unwrap();
+        }
+    }
+    dot.push_str("}\n");
+
+    dot
+}
+
+/// An object with each colour's contents as an object of colours and
+/// counts, on a single line.
+pub fn to_json(graph: &BagGraph, subgraph: Subgraph) -> String {
+    let colors: Vec<String> = edges(graph, subgraph)
+        .into_iter()
+        .map(|(id, contents)| {
+            let contents: Vec<String> = contents
+                .iter()
+                .map(|&(item, count)| format!("{}:{}", json_string(graph.color(item)), count))
+                .collect();
+            format!(
+                "{}:{{{}}}",
+                json_string(graph.color(id)),
+                contents.join(",")
+            )
+        })
+        .collect();
+
+    format!("{{{}}}\n", colors.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_rules, Day07};
+    use aoc_common::Solution;
+
+    const RULES: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain no other bags.
+shiny gold bags contain no other bags.";
+
+    #[test]
+    fn exports_dot() {
+        let graph = Day07::parse(RULES).unwrap();
+        assert_eq!(
+            to_dot(&graph, Subgraph::All),
+            "digraph bags {
+    \"light red\" -> \"bright white\" [label=1];
+    \"light red\" -> \"muted yellow\" [label=2];
+    \"bright white\" -> \"shiny gold\" [label=1];
+    \"muted yellow\";
+    \"shiny gold\";
+}
+"
+        );
+
+        let bright_white = graph.id("bright white").unwrap();
+        assert_eq!(
+            to_dot(&graph, Subgraph::From(bright_white)),
+            "digraph bags {
+    \"bright white\" -> \"shiny gold\" [label=1];
+    \"shiny gold\";
+}
+"
+        );
+    }
+
+    #[test]
+    fn quotes_dot_ids() {
+        assert_eq!(dot_string("shiny gold"), "\"shiny gold\"");
+        assert_eq!(dot_string("\"shiny\" go\\d"), "\"\\\"shiny\\\" go\\\\d\"");
+
+        let graph = Day07::parse(
+            "light \"red\" bags contain 2 shiny gold bags.
+shiny gold bags contain no other bags.",
+        )
+        .unwrap();
+        assert_eq!(
+            to_dot(&graph, Subgraph::All),
+            "digraph bags {
+    \"light \\\"red\\\"\" -> \"shiny gold\" [label=2];
+    \"shiny gold\";
+}
+"
+        );
+    }
+
+    #[test]
+    fn exports_json() {
+        let graph = Day07::parse(RULES).unwrap();
+        let shiny_gold = graph.id("shiny gold").unwrap();
+        assert_eq!(
+            to_json(&graph, Subgraph::Into(shiny_gold)),
+            "{\"light red\":{\"bright white\":1},\"bright white\":{\"shiny gold\":1},\
+             \"shiny gold\":{}}\n"
+        );
+    }
+
+    #[test]
+    fn merges_colors_defined_twice() {
+        let (rules, _) = parse_rules(
+            "light red bags contain 1 shiny gold bag.
+light red bags contain 2 shiny gold bags, 3 muted yellow bags.
+shiny gold bags contain no other bags.
+muted yellow bags contain no other bags.",
+        );
+        let graph = BagGraph::new(&rules);
+
+        assert_eq!(
+            to_json(&graph, Subgraph::All),
+            "{\"light red\":{\"shiny gold\":3,\"muted yellow\":3},\
+             \"shiny gold\":{},\"muted yellow\":{}}\n"
+        );
+        assert_eq!(
+            to_dot(&graph, Subgraph::All),
+            "digraph bags {
+    \"light red\" -> \"shiny gold\" [label=3];
+    \"light red\" -> \"muted yellow\" [label=3];
+    \"shiny gold\";
+    \"muted yellow\";
+}
+"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! The rules as a graph of bag colours, with an edge from each bag to the
+//! bags it contains.
+
+use crate::Rule;
+use aoc_common::Error;
+use std::collections::{HashMap, VecDeque};
+
+/// Index of a colour in a `BagGraph`.
+pub type ColorId = usize;
+
+/// How far a depth first search got with a colour.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Visit {
+    New,
+    /// on the path of the search
+    Active,
+    Done,
+}
+
+#[derive(Debug, Default)]
+pub struct BagGraph {
+    colors: Vec<String>,
+    ids: HashMap<String, ColorId>,
+    /// which colours each bag contains, and how many
+    contains: Vec<Vec<(ColorId, u64)>>,
+    /// in which colours each bag may be contained
+    contained_in: Vec<Vec<ColorId>>,
+}
+
+impl BagGraph {
+    /// The graph of all colours in the rules, including those that are only
+    /// ever contained in other bags.
+    pub fn new(rules: &[Rule]) -> BagGraph {
+        let mut graph = BagGraph::default();
+
+        for rule in rules {
+            let container = graph.intern(&rule.color);
+            for (color, count) in &rule.contents {
+                let item = graph.intern(color);
+                graph.contains[container].push((item, u64::from(*count)));
+                graph.contained_in[item].push(container);
+            }
+        }
+
+        graph
+    }
+
+    fn intern(&mut self, color: &str) -> ColorId {
+        if let Some(&id) = self.ids.get(color) {
+            return id;
+        }
+
+        let id = self.colors.len();
+        self.colors.push(color.to_owned());
+        self.ids.insert(color.to_owned(), id);
+        self.contains.push(Vec::new());
+        self.contained_in.push(Vec::new());
+
+        id
+    }
+
+    pub fn id(&self, color: &str) -> Option<ColorId> {
+        self.ids.get(color).copied()
+    }
+
+    pub fn color(&self, id: ColorId) -> &str {
+        &self.colors[id]
+    }
+
+    /// Number of colours.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// The colours a bag of colour `id` directly contains, with counts.
+    pub fn contents(&self, id: ColorId) -> &[(ColorId, u64)] {
+        &self.contains[id]
+    }
+
+    /// The colours that directly contain a bag of colour `id`.
+    pub fn containers(&self, id: ColorId) -> &[ColorId] {
+        &self.contained_in[id]
+    }
+
+    /// Breadth first search from `id`, in order of discovery and without
+    /// `id` itself unless it's on a cycle.
+    fn reachable<'a, I>(&'a self, id: ColorId, next: impl Fn(ColorId) -> I) -> Vec<ColorId>
+    where
+        I: Iterator<Item = ColorId> + 'a,
+    {
+        let mut seen = vec![false; self.len()];
+        let mut queue: VecDeque<ColorId> = next(id).collect();
+        let mut found = Vec::new();
+
+        while let Some(current) = queue.pop_front() {
+            if !seen[current] {
+                seen[current] = true;
+                found.push(current);
+                queue.extend(next(current));
+            }
+        }
+
+        found
+    }
+
+    /// All colours that may eventually contain a bag of colour `id`.
+    pub fn ancestors(&self, id: ColorId) -> Vec<ColorId> {
+        self.reachable(id, |current| self.contained_in[current].iter().copied())
+    }
+
+    /// All colours a bag of colour `id` eventually contains.
+    pub fn descendants(&self, id: ColorId) -> Vec<ColorId> {
+        self.reachable(id, |current| {
+            self.contains[current].iter().map(|&(item, _)| item)
+        })
+    }
+
+    /// Total number of bags inside a bag of colour `id`. The total for each
+    /// colour is worked out once and reused wherever that colour is inside.
+    ///
+    /// Fails if the total doesn't fit into a `u64`, or if a bag on the way
+    /// contains itself.
+    pub fn count_inside(&self, id: ColorId) -> Result<u64, Error> {
+        let mut visits = vec![Visit::New; self.len()];
+        let mut totals = vec![0u64; self.len()];
+        // the colours still being counted, innermost last, with the next item
+        // of each
+        let mut path: Vec<(ColorId, usize)> = vec![(id, 0)];
+        visits[id] = Visit::Active;
+
+        while let Some(&(current, next)) = path.last() {
+            if let Some(&(item, _)) = self.contains[current].get(next) {
+                path.last_mut().unwrap().1 += 1;
+                match visits[item] {
+                    Visit::New => {
+                        visits[item] = Visit::Active;
+                        path.push((item, 0));
+                    }
+                    Visit::Active => {
+                        return Err(Error::InvalidInput(format!(
+                            "{} bags contain themselves",
+                            self.colors[item]
+                        )))
+                    }
+                    Visit::Done => (),
+                }
+                continue;
+            }
+
+            totals[current] = self.contains[current]
+                .iter()
+                .try_fold(0u64, |total, &(item, n)| {
+                    let bags = totals[item].checked_add(1)?.checked_mul(n)?;
+                    total.checked_add(bags)
+                })
+                .ok_or_else(|| {
+                    Error::InvalidInput(format!(
+                        "too many bags inside {} bags",
+                        self.colors[current]
+                    ))
+                })?;
+            visits[current] = Visit::Done;
+            path.pop();
+        }
+
+        Ok(totals[id])
+    }
+
+    /// The shortest chain of bags from `from` down to `to`, both included,
+    /// or `None` if `from` can't contain `to`.
+    pub fn path(&self, from: ColorId, to: ColorId) -> Option<Vec<ColorId>> {
+        let mut previous: Vec<Option<ColorId>> = vec![None; self.len()];
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                let mut path = vec![to];
+                let mut last = to;
+                while let Some(before) = previous[last] {
+                    path.push(before);
+                    last = before;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for &(item, _) in &self.contains[current] {
+                if previous[item].is_none() && item != from {
+                    previous[item] = Some(current);
+                    queue.push_back(item);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::EXAMPLE;
+    use crate::{parse_rules, Day07};
+    use aoc_common::Solution;
+
+    fn colors(graph: &BagGraph, ids: &[ColorId]) -> Vec<String> {
+        let mut colors: Vec<String> = ids.iter().map(|&id| graph.color(id).to_owned()).collect();
+        colors.sort();
+        colors
+    }
+
+    #[test]
+    fn finds_ancestors_and_descendants() {
+        let graph = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(graph.len(), 9);
+
+        let shiny_gold = graph.id("shiny gold").unwrap();
+        assert_eq!(
+            colors(&graph, &graph.ancestors(shiny_gold)),
+            ["bright white", "dark orange", "light red", "muted yellow"]
+        );
+        assert_eq!(
+            colors(&graph, &graph.descendants(shiny_gold)),
+            ["dark olive", "dotted black", "faded blue", "vibrant plum"]
+        );
+        assert_eq!(graph.count_inside(shiny_gold).unwrap(), 32);
+        assert_eq!(graph.containers(shiny_gold).len(), 2);
+        assert_eq!(graph.id("plaid purple"), None);
+    }
+
+    #[test]
+    fn finds_paths() {
+        let graph = Day07::parse(EXAMPLE).unwrap();
+        let id = |color| graph.id(color).unwrap();
+
+        let path = graph.path(id("light red"), id("faded blue")).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(graph.color(path[1]), "muted yellow");
+        assert_eq!(
+            graph.path(id("shiny gold"), id("shiny gold")),
+            Some(vec![id("shiny gold")])
+        );
+        assert_eq!(graph.path(id("faded blue"), id("shiny gold")), None);
+    }
+
+    #[test]
+    fn handles_long_chains() {
+        let mut rules: String = (0..200_000)
+            .map(|i| format!("color {} bags contain 1 color {} bag.\n", i, i + 1))
+            .collect();
+        rules.push_str("color 200000 bags contain no other bags.\n");
+
+        let graph = Day07::parse(&rules).unwrap();
+        assert_eq!(
+            graph.count_inside(graph.id("color 0").unwrap()).unwrap(),
+            200_000
+        );
+    }
+
+    #[test]
+    fn refuses_cycles() {
+        let (rules, _) = parse_rules(
+            "light red bags contain 1 bright white bag.
+bright white bags contain 2 muted yellow bags, 1 faded blue bag.
+muted yellow bags contain 1 bright white bag.
+faded blue bags contain no other bags.",
+        );
+        let graph = BagGraph::new(&rules);
+        let id = |color| graph.id(color).unwrap();
+
+        assert_eq!(graph.count_inside(id("faded blue")).unwrap(), 0);
+        assert!(matches!(
+            graph.count_inside(id("light red")),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            graph.count_inside(id("muted yellow")),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn refuses_to_overflow() {
+        let graph = Day07::parse(
+            "light red bags contain 4000000000 bright white bags.
+bright white bags contain 4000000000 muted yellow bags.
+muted yellow bags contain 4000000000 shiny gold bags.
+shiny gold bags contain no other bags.",
+        )
+        .unwrap();
+        let id = |color| graph.id(color).unwrap();
+
+        assert_eq!(
+            graph.count_inside(id("bright white")).unwrap(),
+            4_000_000_000 * 4_000_000_001
+        );
+        assert!(matches!(
+            graph.count_inside(id("light red")),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,344 @@
+use aoc_common::{Answer, Error, Parsing, Rejected, Solution};
+use graph::BagGraph;
+use thiserror::Error;
+use validate::checked_graph;
+
+pub mod export;
+pub mod graph;
+pub mod validate;
+
+/// The colour of our own bag.
+pub const SHINY_GOLD: &str = "shiny gold";
+
+/// Which bags a bag of one colour must contain.
+pub struct Rule {
+    /// where the rule was defined, starting at 1
+    pub line: usize,
+    pub color: String,
+    pub contents: Vec<(String, u32)>,
+}
+
+/// What's wrong with a malformed rule.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RuleErrorKind {
+    #[error("expected ' contain '")]
+    MissingContain,
+    #[error("expected a colour followed by 'bag' or 'bags'")]
+    InvalidColor,
+    #[error("expected a positive number")]
+    InvalidCount,
+    #[error("expected 'COUNT COLOR bags'")]
+    InvalidItem,
+}
+
+/// A malformed rule; `line` starts at 1 and `snippet` is the part of it
+/// that couldn't be parsed.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line}: {kind}, found '{snippet}'")]
+pub struct RuleError {
+    pub line: usize,
+    /// the colour the rule is for, if that much could be read
+    pub color: Option<String>,
+    pub snippet: String,
+    pub kind: RuleErrorKind,
+}
+
+/// The colour in `COLOR bag` or `COLOR bags`.
+fn parse_color(text: &str) -> Result<&str, (String, RuleErrorKind)> {
+    let color = text
+        .strip_suffix(" bags")
+        .or_else(|| text.strip_suffix(" bag"))
+        .map(str::trim)
+        .filter(|color| !color.is_empty());
+
+    color.ok_or_else(|| (text.to_owned(), RuleErrorKind::InvalidColor))
+}
+
+/// The colour of a malformed rule, if it starts like `COLOR bags contain`.
+fn rule_color(text: &str) -> Option<String> {
+    let (container, _) = text.trim().split_once(" contain")?;
+    parse_color(container.trim()).ok().map(str::to_owned)
+}
+
+/// Parse a rule like `light red bags contain 1 bright white bag, 2 muted
+/// yellow bags.` on `line`, with or without the period; errors carry the
+/// snippet but not the line.
+fn parse_rule(line: usize, text: &str) -> Result<Rule, (String, RuleErrorKind)> {
+    let text = text.trim();
+    let text = text.strip_suffix('.').unwrap_or(text);
+
+    let (container, contents_text) = text
+        .split_once(" contain ")
+        .ok_or_else(|| (text.to_owned(), RuleErrorKind::MissingContain))?;
+    let color = parse_color(container.trim())?.to_owned();
+
+    let contents_text = contents_text.trim();
+    let contents = if contents_text == "no other bags" || contents_text == "no other bag" {
+        Vec::new()
+    } else {
+        contents_text
+            .split(',')
+            .map(|item| {
+                let item = item.trim();
+                let (count, color) = item
+                    .split_once(' ')
+                    .ok_or_else(|| (item.to_owned(), RuleErrorKind::InvalidItem))?;
+                let count = count
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|&count| count > 0)
+                    .ok_or_else(|| (count.to_owned(), RuleErrorKind::InvalidCount))?;
+
+                Ok((parse_color(color.trim())?.to_owned(), count))
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    Ok(Rule {
+        line,
+        color,
+        contents,
+    })
+}
+
+/// All well-formed rules, and the errors for the malformed ones.
+pub fn parse_rules(input: &str) -> (Vec<Rule>, Vec<RuleError>) {
+    let mut rules = Vec::new();
+    let mut rejected = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match parse_rule(i + 1, line) {
+            Ok(rule) => rules.push(rule),
+            Err((snippet, kind)) => rejected.push(RuleError {
+                line: i + 1,
+                color: rule_color(line),
+                snippet,
+                kind,
+            }),
+        }
+    }
+
+    (rules, rejected)
+}
+
+/// The malformed rules as rejected lines.
+pub fn rejected_lines(errors: &[RuleError]) -> Vec<Rejected> {
+    errors
+        .iter()
+        .map(|error| {
+            Rejected::new(
+                error.line,
+                format!("{}, found '{}'", error.kind, error.snippet),
+            )
+        })
+        .collect()
+}
+
+/// The well-formed rules and the malformed ones as rejected lines, or in
+/// strict mode an error for the first malformed rule.
+pub fn read_rules(input: &str, parsing: Parsing) -> Result<(Vec<Rule>, Vec<Rejected>), Error> {
+    let (rules, errors) = parse_rules(input);
+    parsing.check((rules, rejected_lines(&errors)))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = BagGraph;
+
+    fn parse(input: &str) -> Result<BagGraph, Error> {
+        let (graph, _) = Parsing::Strict.check(Self::parse_lenient(input)?)?;
+        Ok(graph)
+    }
+
+    fn parse_lenient(input: &str) -> Result<(BagGraph, Vec<Rejected>), Error> {
+        let (rules, errors) = parse_rules(input);
+        Ok((checked_graph(&rules, &errors)?, rejected_lines(&errors)))
+    }
+
+    fn part1(graph: &BagGraph) -> Result<Answer, Error> {
+        let count = graph
+            .id(SHINY_GOLD)
+            .map_or(0, |shiny_gold| graph.ancestors(shiny_gold).len());
+
+        Ok(count.into())
+    }
+
+    fn part2(graph: &BagGraph) -> Result<Answer, Error> {
+        let shiny_gold = graph
+            .id(SHINY_GOLD)
+            .ok_or_else(|| Error::InvalidInput(format!("no rule for {} bags", SHINY_GOLD)))?;
+
+        graph.count_inside(shiny_gold).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve, solve_part, Part};
+
+    pub(crate) const EXAMPLE: &str =
+        "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    #[test]
+    fn parses_rules() {
+        let (rules, rejected) = parse_rules(EXAMPLE);
+        assert!(rejected.is_empty());
+        assert_eq!(rules.len(), 9);
+        assert_eq!(rules[0].color, "light red");
+        assert_eq!(
+            rules[0].contents,
+            vec![
+                ("bright white".to_owned(), 1),
+                ("muted yellow".to_owned(), 2)
+            ]
+        );
+        assert!(rules[7].contents.is_empty());
+    }
+
+    #[test]
+    fn accepts_variants() {
+        let rule = parse_rule(
+            3,
+            "light red bag contain 1 bright white bags, 300 muted yellow bag",
+        )
+        .unwrap();
+        assert_eq!(rule.line, 3);
+        assert_eq!(rule.color, "light red");
+        assert_eq!(
+            rule.contents,
+            vec![
+                ("bright white".to_owned(), 1),
+                ("muted yellow".to_owned(), 300)
+            ]
+        );
+        assert!(parse_rule(1, "faded blue bags contain no other bag")
+            .unwrap()
+            .contents
+            .is_empty());
+    }
+
+    #[test]
+    fn locates_malformed_rules() {
+        let (rules, rejected) = parse_rules(
+            "light red bags contains 1 bright white bag.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain two shiny gold bags.
+dark orange bags contain 3 bright white.
+bags contain 2 muted yellow bags.",
+        );
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].line, 2);
+        assert_eq!(
+            rejected,
+            vec![
+                RuleError {
+                    line: 1,
+                    color: Some("light red".to_owned()),
+                    snippet: "light red bags contains 1 bright white bag".to_owned(),
+                    kind: RuleErrorKind::MissingContain
+                },
+                RuleError {
+                    line: 3,
+                    color: Some("muted yellow".to_owned()),
+                    snippet: "two".to_owned(),
+                    kind: RuleErrorKind::InvalidCount
+                },
+                RuleError {
+                    line: 4,
+                    color: Some("dark orange".to_owned()),
+                    snippet: "bright white".to_owned(),
+                    kind: RuleErrorKind::InvalidColor
+                },
+                RuleError {
+                    line: 5,
+                    color: None,
+                    snippet: "bags".to_owned(),
+                    kind: RuleErrorKind::InvalidColor
+                },
+            ]
+        );
+        assert_eq!(
+            rejected[1].to_string(),
+            "line 3: expected a positive number, found 'two'"
+        );
+        assert_eq!(
+            rejected_lines(&rejected)[1],
+            Rejected::new(3, "expected a positive number, found 'two'")
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day07>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(4)
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            solve_part::<Day07>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(32)
+        );
+
+        let example = "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.";
+        assert_eq!(
+            solve_part::<Day07>(example, Part::Two).unwrap(),
+            Answer::Unsigned(126)
+        );
+    }
+
+    #[test]
+    fn counts_bags_inside_any_color() {
+        let graph = Day07::parse(EXAMPLE).unwrap();
+        let id = |color| graph.id(color).unwrap();
+        assert_eq!(graph.count_inside(id("faded blue")).unwrap(), 0);
+        assert_eq!(graph.count_inside(id("dark olive")).unwrap(), 7);
+    }
+
+    #[test]
+    fn solves_around_malformed_rules() {
+        let input = EXAMPLE.replace("contain 3 faded blue", "contain three faded blue");
+
+        let report = solve::<Day07>(&input, &Part::ALL, Parsing::Lenient).unwrap();
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].line, 6);
+        assert_eq!(
+            *report.parts[0].answer.as_ref().unwrap(),
+            Answer::Unsigned(4)
+        );
+        // the dark olive bags have no known contents anymore
+        assert_eq!(
+            *report.parts[1].answer.as_ref().unwrap(),
+            Answer::Unsigned(25)
+        );
+
+        assert!(matches!(
+            solve::<Day07>(&input, &Part::ALL, Parsing::Strict),
+            Err(Error::Parse { line: 6, .. })
+        ));
+    }
+}
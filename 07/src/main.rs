@@ -1,120 +1,238 @@
-use std::collections::HashSet;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-fn read_input(filename: &str) -> String {
-    let path = Path::new(filename);
-    let display = path.display();
-
-    let mut file = match File::open(&path) {
-        Err(why) => panic!("couldn't open {}: {}", display, why),
-        Ok(file) => file,
+//! ```text
+//! aoc-2020-07 [--strict] [--format text|json] [-v|-vv]
+//! aoc-2020-07 inside COLOR [--strict] [--input PATH]
+//! aoc-2020-07 ancestors COLOR [--strict] [--input PATH]
+//! aoc-2020-07 descendants COLOR [--strict] [--input PATH]
+//! aoc-2020-07 path FROM TO [--strict] [--input PATH]
+//! aoc-2020-07 validate [--strict] [--input PATH]
+//! aoc-2020-07 export [--strict] [--format dot|json] [--from COLOR|--into COLOR] [--input PATH]
+//! ```
+//!
+//! Malformed rules are left out and listed on stderr with their line. With
+//! `--strict`, any malformed rule fails the run instead.
+//!
+//! `inside` prints how many bags a bag of the given colour, e.g.
+//! `shiny gold`, contains in total. `ancestors` lists the colours that may
+//! eventually contain it, `descendants` the colours it eventually contains.
+//! `path` prints the shortest chain of bags from one colour down to
+//! another; its colours need quotes, e.g. `path "light red" "faded blue"`.
+//!
+//! All of these refuse rules with cycles, colours defined twice or colours
+//! that are never defined, unless they may be defined by a malformed rule.
+//! `validate` lists every such problem.
+//!
+//! `export` prints the rules as a Graphviz graph (the default) or as JSON,
+//! optionally only the colours a bag of `--from` eventually contains or
+//! those that may eventually contain a bag of `--into`. It works on invalid
+//! rules too, so cycles can be looked at.
+
+use aoc_2020_07::export::{self, Subgraph};
+use aoc_2020_07::graph::{BagGraph, ColorId};
+use aoc_2020_07::validate::validate;
+use aoc_2020_07::{read_rules, Day07, Rule};
+use aoc_common::{rejected_summary, Error, Parsing, Rejected, Solution};
+use std::env;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Dot,
+    Json,
+}
+
+/// Colours for `export` to start from.
+enum Start {
+    From(String),
+    Into(String),
+}
+
+struct Options {
+    input: PathBuf,
+    parsing: Parsing,
+    format: ExportFormat,
+    start: Option<Start>,
+    /// the words of a colour
+    words: Vec<String>,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, Error> {
+    let mut options = Options {
+        input: PathBuf::from("input.txt"),
+        parsing: Parsing::Lenient,
+        format: ExportFormat::Dot,
+        start: None,
+        words: Vec::new(),
     };
 
-    let mut input = String::new();
-    match file.read_to_string(&mut input) {
-        Err(why) => panic!("couldn't read {}: {}", display, why),
-        Ok(_) => (),
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::InvalidArgument(format!("{} needs a value", arg)))
+        };
+
+        match arg.as_str() {
+            "--input" => options.input = PathBuf::from(value()?),
+            "--strict" => options.parsing = Parsing::Strict,
+            "--format" => {
+                options.format = match value()?.as_str() {
+                    "dot" => ExportFormat::Dot,
+                    "json" => ExportFormat::Json,
+                    other => {
+                        return Err(Error::InvalidArgument(format!(
+                            "unknown format '{}'",
+                            other
+                        )))
+                    }
+                }
+            }
+            "--from" => options.start = Some(Start::From(value()?.to_owned())),
+            "--into" => options.start = Some(Start::Into(value()?.to_owned())),
+            word => options.words.push(word.to_owned()),
+        }
     }
 
-    input
-}
-
-/*
-struct Bag {
-    /// the color of this bag
-    color: &str,
-    /// in which bags it may be contained
-    contained_in: mut HashSet<&str>,
-    /// which color of bags it can contain and how many
-    contains: mut HashMap<&str, u8>,
-}
-*/
-
-// note to self: lifetime annotation <'a> means that a Rule struct cannot exceed
-// the lifetime of 'a, so a rule 'dies' lives _at most_ as long as the `color`
-// string and any string in its `contents` vector
-struct Rule<'a> {
-    color: &'a str,
-    contents: Vec<(&'a str, u8)>,
-}
-
-fn parse_rule(rule_text: &str) -> Option<Rule> {
-    if let [container, contents_text] = rule_text.splitn(2, " contain ").collect::<Vec<&str>>()[..2]
-    {
-        let color_end = container.find("bag")?;
-        let color = container[..color_end].trim();
-
-        let contents: Vec<(&str, u8)> = if contents_text.starts_with("no other bags") {
-            vec![]
-        } else {
-            contents_text
-                .split(", ")
-                .map(|item| {
-                    let count_string = item.split_whitespace().nth(0).unwrap();
-                    let count = count_string.parse::<u8>().ok().unwrap();
-
-                    let color = match (item.find(" "), item.find(" bag")) {
-                        (Some(start), Some(end)) => &item[start + 1..end],
-                        _ => panic!("unexpected color format {}", item),
-                    };
-
-                    (color, count)
-                })
-                .collect()
-        };
+    Ok(options)
+}
+
+fn inside(options: &Options) -> Result<String, Error> {
+    if options.words.is_empty() {
+        return Err(Error::InvalidArgument("inside needs a colour".to_owned()));
+    }
 
-        Some(Rule { color, contents })
-    } else {
-        None
+    let graph = load_graph(options)?;
+    let id = find(&graph, &options.words.join(" "))?;
+
+    Ok(format!("{}\n", graph.count_inside(id)?))
+}
+
+/// Lists the rejected lines on stderr, so they don't mix with the output.
+fn report(rejected: &[Rejected]) {
+    if !rejected.is_empty() {
+        eprint!("{}", rejected_summary(rejected));
     }
 }
 
-fn main() {
-    let input = read_input("input.txt");
-
-    // test input
-    /*
-        let input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
-    dark orange bags contain 3 bright white bags, 4 muted yellow bags.
-    bright white bags contain 1 shiny gold bag.
-    muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
-    shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
-    dark olive bags contain 3 faded blue bags, 4 dotted black bags.
-    vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
-    faded blue bags contain no other bags.
-    dotted black bags contain no other bags.";
-    */
-
-    let rules: Vec<Rule> = input
-        .lines()
-        .map(|ref rule| parse_rule(rule).unwrap())
-        .collect();
-
-    let mut containing_bags = HashSet::new();
-    containing_bags.insert("shiny gold");
-
-    loop {
-        let previous_size = containing_bags.len();
-
-        for Rule { color, contents } in &rules {
-            if contents
-                .iter()
-                .any(|(item_color, ..)| containing_bags.contains(item_color))
-            {
-                containing_bags.insert(color);
-            }
-        }
+fn load_rules(options: &Options) -> Result<Vec<Rule>, Error> {
+    let input = aoc_common::read_input(&options.input)?;
+    let (rules, rejected) = read_rules(&input, options.parsing)?;
+    report(&rejected);
+
+    Ok(rules)
+}
+
+fn load_graph(options: &Options) -> Result<BagGraph, Error> {
+    let input = aoc_common::read_input(&options.input)?;
+    let (graph, rejected) = options.parsing.check(Day07::parse_lenient(&input)?)?;
+    report(&rejected);
+
+    Ok(graph)
+}
+
+fn find(graph: &BagGraph, color: &str) -> Result<ColorId, Error> {
+    graph
+        .id(color)
+        .ok_or_else(|| Error::InvalidArgument(format!("unknown colour '{}'", color)))
+}
+
+fn list(graph: &BagGraph, ids: Vec<ColorId>) -> String {
+    let mut colors: Vec<&str> = ids.into_iter().map(|id| graph.color(id)).collect();
+    colors.sort_unstable();
 
-        if previous_size >= containing_bags.len() {
-            break;
+    colors.iter().map(|color| format!("{}\n", color)).collect()
+}
+
+fn ancestors(options: &Options) -> Result<String, Error> {
+    let graph = load_graph(options)?;
+    let id = find(&graph, &options.words.join(" "))?;
+
+    Ok(list(&graph, graph.ancestors(id)))
+}
+
+fn descendants(options: &Options) -> Result<String, Error> {
+    let graph = load_graph(options)?;
+    let id = find(&graph, &options.words.join(" "))?;
+
+    Ok(list(&graph, graph.descendants(id)))
+}
+
+fn path(options: &Options) -> Result<String, Error> {
+    let (from, to) = match &options.words[..] {
+        [from, to] => (from, to),
+        _ => {
+            return Err(Error::InvalidArgument(
+                "path needs exactly two colours".to_owned(),
+            ))
         }
+    };
+
+    let graph = load_graph(options)?;
+    let path = graph
+        .path(find(&graph, from)?, find(&graph, to)?)
+        .ok_or_else(|| Error::NoSolution(format!("{} bags can't contain {} bags", from, to)))?;
+    let colors: Vec<&str> = path.into_iter().map(|id| graph.color(id)).collect();
+
+    Ok(format!("{}\n", colors.join(" -> ")))
+}
+
+fn validate_rules(options: &Options) -> Result<String, Error> {
+    if let Some(word) = options.words.first() {
+        return Err(Error::InvalidArgument(format!(
+            "unexpected argument '{}'",
+            word
+        )));
     }
 
-    // omit "shiny gold" itself from the results
-    println!(
-        "PART I: a shiny gold bag may be contained in {} different bags",
-        containing_bags.len() - 1
-    )
+    let rules = load_rules(options)?;
+    let problems = validate(&rules, &BagGraph::new(&rules));
+    if problems.is_empty() {
+        return Ok(format!("all {} rules are valid\n", rules.len()));
+    }
+
+    let problems: Vec<String> = problems.iter().map(|problem| problem.to_string()).collect();
+    Err(Error::InvalidInput(format!(
+        "{} problems in the rules:\n{}",
+        problems.len(),
+        problems.join("\n")
+    )))
+}
+
+fn export(options: &Options) -> Result<String, Error> {
+    if let Some(word) = options.words.first() {
+        return Err(Error::InvalidArgument(format!(
+            "unexpected argument '{}'",
+            word
+        )));
+    }
+
+    let graph = BagGraph::new(&load_rules(options)?);
+    let subgraph = match &options.start {
+        None => Subgraph::All,
+        Some(Start::From(color)) => Subgraph::From(find(&graph, color)?),
+        Some(Start::Into(color)) => Subgraph::Into(find(&graph, color)?),
+    };
+
+    Ok(match options.format {
+        ExportFormat::Dot => export::to_dot(&graph, subgraph),
+        ExportFormat::Json => export::to_json(&graph, subgraph),
+    })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command: fn(&Options) -> Result<String, Error> = match args.first().map(String::as_str) {
+        Some("inside") => inside,
+        Some("ancestors") => ancestors,
+        Some("descendants") => descendants,
+        Some("path") => path,
+        Some("validate") => validate_rules,
+        Some("export") => export,
+        _ => return aoc_common::run_with_args::<Day07>(args.into_iter()),
+    };
+
+    match parse_options(&args[1..]).and_then(|options| command(&options)) {
+        Ok(output) => print!("{}", output),
+        Err(why) => aoc_common::fail(Day07::DAY, why),
+    }
 }
@@ -0,0 +1,212 @@
+//! Checks that the rules make sense before anything is counted with them.
+
+use crate::graph::{BagGraph, ColorId, Visit};
+use crate::{Rule, RuleError};
+use aoc_common::Error;
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// the colours on the cycle, starting and ending with the same one
+    #[error("bags contain themselves: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+    #[error("{color} bags are defined on line {first} and again on line {line}")]
+    Duplicate {
+        color: String,
+        first: usize,
+        line: usize,
+    },
+    #[error("{color} bags on line {line} are never defined")]
+    Dangling { color: String, line: usize },
+}
+
+/// Every colour defined twice or used without being defined, in order of
+/// the rules, and every cycle of bags containing each other. `graph` is the
+/// graph of `rules`.
+pub fn validate(rules: &[Rule], graph: &BagGraph) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    let mut defined = HashMap::new();
+    for rule in rules {
+        if let Some(&first) = defined.get(rule.color.as_str()) {
+            problems.push(Problem::Duplicate {
+                color: rule.color.clone(),
+                first,
+                line: rule.line,
+            });
+        } else {
+            defined.insert(rule.color.as_str(), rule.line);
+        }
+    }
+
+    let mut reported = HashSet::new();
+    for rule in rules {
+        for (color, _) in &rule.contents {
+            if !defined.contains_key(color.as_str()) && reported.insert(color) {
+                problems.push(Problem::Dangling {
+                    color: color.clone(),
+                    line: rule.line,
+                });
+            }
+        }
+    }
+
+    problems.extend(cycles(graph).into_iter().map(|cycle| {
+        Problem::Cycle(
+            cycle
+                .into_iter()
+                .map(|id| graph.color(id).to_owned())
+                .collect(),
+        )
+    }));
+
+    problems
+}
+
+/// One cycle for each edge that closes a cycle in a depth first search.
+fn cycles(graph: &BagGraph) -> Vec<Vec<ColorId>> {
+    let mut visits = vec![Visit::New; graph.len()];
+    let mut cycles = Vec::new();
+    // the active colours, each with the index of the next item to look at
+    let mut path: Vec<(ColorId, usize)> = Vec::new();
+
+    for start in 0..graph.len() {
+        if visits[start] != Visit::New {
+            continue;
+        }
+        visits[start] = Visit::Active;
+        path.push((start, 0));
+
+        while let Some(&(id, next)) = path.last() {
+            let item = match graph.contents(id).get(next) {
+                Some(&(item, _)) => item,
+                None => {
+                    path.pop();
+                    visits[id] = Visit::Done;
+                    continue;
+                }
+            };
+            path.last_mut().unwrap().1 += 1;
+
+            match visits[item] {
+                Visit::New => {
+                    visits[item] = Visit::Active;
+                    path.push((item, 0));
+                }
+                Visit::Active => {
+                    let start = path
+                        .iter()
+                        .position(|&(on_path, _)| on_path == item)
+                        .unwrap();
+                    let mut cycle: Vec<ColorId> = path[start..].iter().map(|&(id, _)| id).collect();
+                    cycle.push(item);
+                    cycles.push(cycle);
+                }
+                Visit::Done => (),
+            }
+        }
+    }
+
+    cycles
+}
+
+/// Whether `color` may be defined by one of the `skipped` rules.
+fn maybe_skipped(skipped: &[RuleError], color: &str) -> bool {
+    skipped.iter().any(|error| {
+        error
+            .color
+            .as_deref()
+            .is_none_or(|skipped| skipped == color)
+    })
+}
+
+/// The graph of the rules, or an error listing all their problems. Colours
+/// that may be defined by one of the malformed `skipped` rules are not a
+/// problem when they are never defined; they count as empty bags.
+pub fn checked_graph(rules: &[Rule], skipped: &[RuleError]) -> Result<BagGraph, Error> {
+    let graph = BagGraph::new(rules);
+    let problems: Vec<Problem> = validate(rules, &graph)
+        .into_iter()
+        .filter(|problem| match problem {
+            Problem::Dangling { color, .. } => !maybe_skipped(skipped, color),
+            _ => true,
+        })
+        .collect();
+    if problems.is_empty() {
+        return Ok(graph);
+    }
+
+    let problems: Vec<String> = problems.iter().map(Problem::to_string).collect();
+    Err(Error::InvalidInput(format!(
+        "invalid rules: {}",
+        problems.join("; ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_rules;
+    use crate::tests::EXAMPLE;
+
+    #[test]
+    fn accepts_example() {
+        let (rules, _) = parse_rules(EXAMPLE);
+        assert_eq!(validate(&rules, &BagGraph::new(&rules)), vec![]);
+    }
+
+    #[test]
+    fn reports_problems() {
+        let (rules, _) = parse_rules(
+            "light red bags contain 1 bright white bag.
+bright white bags contain 2 muted yellow bags, 1 dark olive bag.
+muted yellow bags contain 1 light red bag.
+light red bags contain no other bags.",
+        );
+
+        assert_eq!(
+            validate(&rules, &BagGraph::new(&rules)),
+            vec![
+                Problem::Duplicate {
+                    color: "light red".to_owned(),
+                    first: 1,
+                    line: 4
+                },
+                Problem::Dangling {
+                    color: "dark olive".to_owned(),
+                    line: 2
+                },
+                Problem::Cycle(vec![
+                    "light red".to_owned(),
+                    "bright white".to_owned(),
+                    "muted yellow".to_owned(),
+                    "light red".to_owned()
+                ]),
+            ]
+        );
+        assert!(matches!(
+            checked_graph(&rules, &[]),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn excuses_colors_of_skipped_rules() {
+        let uses = "light red bags contain 1 dark olive bag, 2 shiny gold bags.
+shiny gold bags contain no other bags.
+";
+
+        let (rules, skipped) = parse_rules(&format!("{}dark olive bags contain some bags.", uses));
+        assert!(checked_graph(&rules, &skipped).is_ok());
+
+        let (rules, skipped) = parse_rules(&format!("{}dark olive", uses));
+        assert!(checked_graph(&rules, &skipped).is_ok());
+
+        let (rules, skipped) = parse_rules(&format!("{}faded blue bags contain some bags.", uses));
+        assert!(matches!(
+            checked_graph(&rules, &skipped),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
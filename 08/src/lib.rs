@@ -0,0 +1,171 @@
+use aoc_common::{Answer, Error, Solution};
+use std::collections::HashSet;
+
+enum Stop {
+    Terminated(i32),
+    LoopDetected(i32),
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Accumulate(i32),
+    Nop(i32),
+    Jump(i32),
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Accumulate(value) => write!(f, "acc {}", value),
+            Instruction::Jump(offset) => write!(f, "jmp {}", offset),
+            Instruction::Nop(value) => write!(f, "nop {}", value),
+        }
+    }
+}
+
+fn decode(instruction: &str) -> Result<Instruction, String> {
+    let (opcode, argument) = instruction
+        .split_once(' ')
+        .ok_or_else(|| format!("invalid instruction '{}'", instruction))?;
+    let argument = argument
+        .parse::<i32>()
+        .map_err(|why| format!("invalid argument '{}': {}", argument, why))?;
+
+    match opcode {
+        "acc" => Ok(Instruction::Accumulate(argument)),
+        "nop" => Ok(Instruction::Nop(argument)),
+        "jmp" => Ok(Instruction::Jump(argument)),
+        _ => Err(format!("invalid opcode '{}'", opcode)),
+    }
+}
+
+fn run(instructions: &[Instruction]) -> Result<Stop, ()> {
+    let mut program_counter = 0;
+    let mut accumulator = 0;
+
+    let mut visited_instructions: HashSet<usize> = HashSet::new();
+
+    while program_counter < instructions.len() {
+        if visited_instructions.contains(&program_counter) {
+            return Ok(Stop::LoopDetected(accumulator));
+        }
+        visited_instructions.insert(program_counter);
+
+        let instruction = &instructions[program_counter];
+        match instruction {
+            Instruction::Accumulate(value) => {
+                accumulator += value;
+                program_counter += 1;
+            }
+            Instruction::Nop(_) => {
+                program_counter += 1;
+            }
+            Instruction::Jump(offset) => {
+                program_counter = if offset.is_negative() {
+                    program_counter
+                        .checked_sub(offset.wrapping_abs() as usize)
+                        .unwrap()
+                } else {
+                    program_counter.checked_add(*offset as usize).unwrap()
+                };
+            }
+        }
+    }
+
+    Ok(Stop::Terminated(accumulator))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| decode(line).map_err(|why| Error::parse(i + 1, why)))
+            .collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<Answer, Error> {
+        match run(instructions) {
+            Ok(Stop::LoopDetected(accumulator)) => Ok(accumulator.into()),
+            _ => Err(Error::NoSolution(
+                "program terminated without reaching a duplicate instruction".to_owned(),
+            )),
+        }
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<Answer, Error> {
+        // brute force approach - replace any single nop/jmp, see if program execution terminates
+        for line_no in 0..instructions.len() {
+            let modified_instructions: Vec<Instruction> = instructions
+                .iter()
+                .enumerate()
+                .map(|(n, i)| {
+                    if n == line_no {
+                        match i {
+                            Instruction::Nop(v) => Instruction::Jump(*v),
+                            Instruction::Jump(offset) => Instruction::Nop(*offset),
+                            _ => *i,
+                        }
+                    } else {
+                        *i
+                    }
+                })
+                .collect();
+
+            if let Ok(Stop::Terminated(accumulator)) = run(&modified_instructions) {
+                return Ok(accumulator.into());
+            }
+        }
+
+        Err(Error::NoSolution(
+            "no single nop/jmp swap makes the program terminate".to_owned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn decodes_instructions() {
+        assert!(matches!(decode("acc +1"), Ok(Instruction::Accumulate(1))));
+        assert!(matches!(decode("jmp -3"), Ok(Instruction::Jump(-3))));
+        assert!(matches!(decode("nop +0"), Ok(Instruction::Nop(0))));
+        assert!(decode("mul +2").is_err());
+        assert!(decode("acc").is_err());
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day08>(EXAMPLE, Part::One).unwrap(),
+            Answer::Signed(5)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day08>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Signed(8)
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_common::{Answer, Error, Solution};
+use std::ops::Range;
+
+fn is_sum(numbers: &[u64], value: &u64) -> bool {
+    for a in numbers {
+        for b in numbers {
+            if a == b {
+                continue;
+            }
+            if a + b == *value {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn find_summand_sequence(numbers: &[u64], number: &u64) -> Option<Range<usize>> {
+    for start in 0..numbers.len() {
+        for end in start + 1..numbers.len() {
+            if numbers[start..end].iter().sum::<u64>() == *number {
+                return Some(start..end);
+            }
+        }
+    }
+
+    None
+}
+
+const PREAMBLE_LENGTH: usize = 25;
+
+fn find_invalid_number(numbers: &[u64], preamble_length: usize) -> Result<u64, Error> {
+    numbers
+        .iter()
+        .enumerate()
+        .skip(preamble_length)
+        .find(|(i, n)| !is_sum(&numbers[i - preamble_length..*i], n))
+        .map(|(_, n)| *n)
+        .ok_or_else(|| Error::NoSolution("found no invalid number".to_owned()))
+}
+
+/// Sum of the smallest and largest number in the contiguous range that adds
+/// up to the first invalid number.
+fn find_weakness(numbers: &[u64], preamble_length: usize) -> Result<u64, Error> {
+    let invalid_number = find_invalid_number(numbers, preamble_length)?;
+
+    let range = find_summand_sequence(numbers, &invalid_number)
+        .ok_or_else(|| Error::NoSolution("couldn't find a sequence of summands".to_owned()))?;
+
+    let summands = &numbers[range];
+    Ok(summands.iter().min().unwrap() + summands.iter().max().unwrap())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, s)| s.parse::<u64>().map_err(|why| Error::parse(i + 1, why)))
+            .collect()
+    }
+
+    fn part1(numbers: &Vec<u64>) -> Result<Answer, Error> {
+        Ok(find_invalid_number(numbers, PREAMBLE_LENGTH)?.into())
+    }
+
+    fn part2(numbers: &Vec<u64>) -> Result<Answer, Error> {
+        Ok(find_weakness(numbers, PREAMBLE_LENGTH)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the example uses a preamble of 5 instead of 25 numbers
+    const EXAMPLE: &str = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+    #[test]
+    fn part1_example() {
+        let numbers = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(find_invalid_number(&numbers, 5).unwrap(), 127);
+    }
+
+    #[test]
+    fn part2_example() {
+        let numbers = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(find_summand_sequence(&numbers, &127), Some(2..6));
+        assert_eq!(find_weakness(&numbers, 5).unwrap(), 62);
+    }
+
+    #[test]
+    fn rejects_non_numbers() {
+        assert!(matches!(
+            Day09::parse("1\n2\nthree"),
+            Err(Error::Parse { line: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+use aoc_common::{Answer, Error, Solution};
+use std::collections::HashMap;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Vec<i32>;
+
+    /// Parse the adapter joltages and add the charging outlet (0) and the
+    /// device's built-in adapter (highest + 3), sorted ascending.
+    fn parse(input: &str) -> Result<Vec<i32>, Error> {
+        let mut joltages = input
+            .lines()
+            .enumerate()
+            .map(|(i, s)| s.parse::<i32>().map_err(|why| Error::parse(i + 1, why)))
+            .collect::<Result<Vec<i32>, Error>>()?;
+        joltages.sort();
+
+        joltages.insert(0, 0);
+        joltages.push(joltages.last().unwrap() + 3);
+
+        Ok(joltages)
+    }
+
+    fn part1(joltages: &Vec<i32>) -> Result<Answer, Error> {
+        let differences: Vec<i32> = joltages
+            .iter()
+            .skip(1)
+            .zip(joltages.iter())
+            .map(|(a, b)| a - b)
+            .collect();
+
+        let mut bins: HashMap<&i32, u16> = HashMap::new();
+        for d in &differences {
+            let count = bins.entry(d).or_insert(0);
+            *count += 1;
+        }
+
+        Ok((*bins.get(&1).unwrap_or(&0) * bins.get(&3).unwrap_or(&0)).into())
+    }
+
+    fn part2(joltages: &Vec<i32>) -> Result<Answer, Error> {
+        // approach 2: "dynamic programming"
+        // cache existing calculations
+        let mut path_counts: HashMap<&i32, usize> = HashMap::new();
+        path_counts.insert(joltages.last().unwrap(), 1);
+        for j in joltages.iter().rev().skip(1) {
+            path_counts.insert(
+                j,
+                (j + 1..=j + 3)
+                    .map(|nj| *path_counts.get(&nj).unwrap_or(&0))
+                    .sum(),
+            );
+        }
+
+        Ok((*path_counts.get(joltages.first().unwrap()).unwrap()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE_1: &str = "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4";
+
+    const EXAMPLE_2: &str = "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3";
+
+    #[test]
+    fn adds_outlet_and_device() {
+        let joltages = Day10::parse(EXAMPLE_1).unwrap();
+        assert_eq!(joltages.first(), Some(&0));
+        assert_eq!(joltages.last(), Some(&22));
+        assert_eq!(joltages.len(), 13);
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(
+            solve_part::<Day10>(EXAMPLE_1, Part::One).unwrap(),
+            Answer::Unsigned(7 * 5)
+        );
+        assert_eq!(
+            solve_part::<Day10>(EXAMPLE_2, Part::One).unwrap(),
+            Answer::Unsigned(22 * 10)
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            solve_part::<Day10>(EXAMPLE_1, Part::Two).unwrap(),
+            Answer::Unsigned(8)
+        );
+        assert_eq!(
+            solve_part::<Day10>(EXAMPLE_2, Part::Two).unwrap(),
+            Answer::Unsigned(19208)
+        );
+    }
+}
@@ -0,0 +1,443 @@
+use aoc_common::{Answer, Error, Solution};
+use std::cmp;
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl fmt::Debug for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Floor => ".",
+            Tile::EmptySeat => "L",
+            Tile::OccupiedSeat => "#",
+        };
+        f.write_str(c)
+    }
+}
+
+#[derive(PartialEq)]
+pub struct State {
+    layout: Vec<Vec<Tile>>,
+}
+
+impl State {
+    fn get_seat(&self, row_index: usize, seat_index: usize) -> &Tile {
+        self.layout
+            .get(row_index)
+            .and_then(|row| row.get(seat_index))
+            .unwrap_or(&Tile::Floor)
+    }
+
+    fn get_neighbors(&self, row_index: usize, seat_index: usize) -> Vec<&Tile> {
+        let mut v = Vec::with_capacity(8);
+        if row_index >= 1 {
+            if seat_index >= 1 {
+                v.push(self.get_seat(row_index - 1, seat_index - 1));
+            }
+            v.push(self.get_seat(row_index - 1, seat_index));
+            v.push(self.get_seat(row_index - 1, seat_index + 1));
+        }
+
+        if seat_index >= 1 {
+            v.push(self.get_seat(row_index, seat_index - 1));
+            v.push(self.get_seat(row_index + 1, seat_index - 1));
+        }
+
+        v.push(self.get_seat(row_index, seat_index + 1));
+
+        v.push(self.get_seat(row_index + 1, seat_index));
+        v.push(self.get_seat(row_index + 1, seat_index + 1));
+
+        v
+    }
+
+    fn occupied_count(&self, row_index: usize, seat_index: usize) -> usize {
+        let mut occupied_seats = 0;
+
+        //north
+        {
+            let end = row_index;
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index - step, seat_index);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+
+        //northeast
+        {
+            let end = cmp::min(row_index, self.layout[0].len() - (seat_index + 1));
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index - step, seat_index + step);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+        //east
+        {
+            let end = self.layout[0].len() - (seat_index + 1);
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index, seat_index + step);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+
+        //southeast
+        {
+            let end = cmp::min(
+                self.layout.len() - (row_index + 1),
+                self.layout[0].len() - (seat_index + 1),
+            );
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index + step, seat_index + step);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+
+        //south
+        {
+            let end = self.layout.len() - (row_index + 1);
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index + step, seat_index);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+
+        //southwest
+        {
+            let end = cmp::min(self.layout.len() - (row_index + 1), seat_index);
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index + step, seat_index - step);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+
+        //west
+        {
+            let end = seat_index;
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index, seat_index - step);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+
+        //northwest
+        {
+            let end = cmp::min(row_index, seat_index);
+            let mut occupied = false;
+            for step in 1..=end {
+                let seat = self.get_seat(row_index - step, seat_index - step);
+                match seat {
+                    Tile::OccupiedSeat => {
+                        occupied = true;
+                        break;
+                    }
+                    // ignore any occupied seats after the first
+                    Tile::EmptySeat => {
+                        break;
+                    }
+                    _ => (),
+                }
+            }
+            if occupied {
+                occupied_seats += 1;
+            }
+        }
+
+        occupied_seats
+    }
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.layout {
+            for seat in row {
+                f.write_fmt(format_args!("{:?}", &seat))?;
+            }
+            f.write_str("\n")?;
+        }
+
+        fmt::Result::Ok(())
+    }
+}
+
+fn step(state: &State) -> State {
+    let next = |ri, si, seat| {
+        let neighbors = state.get_neighbors(ri, si);
+        let occupied_seats = neighbors
+            .iter()
+            .filter(|s| matches!(s, Tile::OccupiedSeat))
+            .count();
+
+        match seat {
+            Tile::EmptySeat => {
+                if occupied_seats == 0 {
+                    Tile::OccupiedSeat
+                } else {
+                    Tile::EmptySeat
+                }
+            }
+            Tile::OccupiedSeat => {
+                if occupied_seats >= 4 {
+                    Tile::EmptySeat
+                } else {
+                    Tile::OccupiedSeat
+                }
+            }
+            other => other,
+        }
+    };
+
+    State {
+        layout: state
+            .layout
+            .iter()
+            .enumerate()
+            .map(|(ri, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(si, seat)| next(ri, si, *seat))
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+fn step_pt2(state: &State) -> State {
+    let next = |ri, si, seat| {
+        let occupied_seats = state.occupied_count(ri, si);
+
+        match seat {
+            Tile::EmptySeat => {
+                if occupied_seats == 0 {
+                    Tile::OccupiedSeat
+                } else {
+                    Tile::EmptySeat
+                }
+            }
+            Tile::OccupiedSeat => {
+                if occupied_seats >= 5 {
+                    Tile::EmptySeat
+                } else {
+                    Tile::OccupiedSeat
+                }
+            }
+            other => other,
+        }
+    };
+
+    State {
+        layout: state
+            .layout
+            .iter()
+            .enumerate()
+            .map(|(ri, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(si, seat)| next(ri, si, *seat))
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+/*
+make_row = map(compose(
+    chars,
+    map(|c| match c {...}),
+    collect
+));
+
+make_layout = compose(lines, make_row, collect);
+*/
+
+/// Apply `step` until the layout doesn't change anymore and count the
+/// occupied seats in the final layout.
+fn occupied_at_equilibrium(initial_state: &State, step: fn(&State) -> State) -> usize {
+    let mut state = State {
+        layout: initial_state.layout.clone(),
+    };
+    let mut next = step(&state);
+    let mut steps = 1;
+
+    while state != next {
+        state = next;
+        next = step(&state);
+        steps += 1;
+    }
+    aoc_common::debug!("{} steps until equilibrium", steps);
+
+    state
+        .layout
+        .iter()
+        .map(|row| row.iter().filter(|&s| *s == Tile::OccupiedSeat).count())
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = State;
+
+    fn parse(input: &str) -> Result<State, Error> {
+        let layout = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l.chars()
+                    .map(|c| match c {
+                        '.' => Ok(Tile::Floor),
+                        'L' => Ok(Tile::EmptySeat),
+                        '#' => Ok(Tile::OccupiedSeat),
+                        _ => Err(Error::parse(i + 1, format!("invalid tile '{}'", c))),
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(State { layout })
+    }
+
+    fn part1(initial_state: &State) -> Result<Answer, Error> {
+        Ok(occupied_at_equilibrium(initial_state, step).into())
+    }
+
+    fn part2(initial_state: &State) -> Result<Answer, Error> {
+        Ok(occupied_at_equilibrium(initial_state, step_pt2).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = include_str!("../input_example_1.txt");
+
+    #[test]
+    fn parses_layout() {
+        let state = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(state.layout.len(), 10);
+        assert!(state.layout.iter().all(|row| row.len() == 10));
+        assert!(Day11::parse("L.X").is_err());
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day11>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(37)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day11>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(26)
+        );
+    }
+}
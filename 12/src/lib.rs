@@ -0,0 +1,280 @@
+use aoc_common::{Answer, Error, Solution};
+use std::f32::consts;
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Action {
+    North,
+    South,
+    East,
+    West,
+    Left,
+    Right,
+    Forward,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Instruction {
+    action: Action,
+    value: f32,
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, String> {
+    let mut chars = line.chars();
+    let action = match chars.next() {
+        Some('N') => Action::North,
+        Some('S') => Action::South,
+        Some('E') => Action::East,
+        Some('W') => Action::West,
+        Some('L') => Action::Left,
+        Some('R') => Action::Right,
+        Some('F') => Action::Forward,
+        _ => return Err(format!("unknown instruction: {}", line)),
+    };
+    let value = chars
+        .as_str()
+        .parse::<f32>()
+        .map_err(|why| format!("invalid value in '{}': {}", line, why))?;
+
+    Ok(Instruction { action, value })
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Vector {
+    x: f32,
+    y: f32,
+}
+
+impl Vector {
+    fn turn(&self, degrees: f32) -> Vector {
+        let radians = degrees / 180.0 * consts::PI;
+        Vector {
+            x: radians.cos() * self.x - radians.sin() * self.y,
+            y: radians.sin() * self.x + radians.cos() * self.y,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Ship {
+    x: f32,
+    y: f32,
+    direction: Direction,
+    waypoint: Vector,
+}
+
+fn navigate(ship: &Ship, instruction: &Instruction) -> Ship {
+    let distance = instruction.value;
+
+    match instruction.action {
+        Action::Forward => match ship.direction {
+            Direction::North => Ship {
+                y: ship.y - distance,
+                ..*ship
+            },
+            Direction::South => Ship {
+                y: ship.y + distance,
+                ..*ship
+            },
+            Direction::East => Ship {
+                x: ship.x + distance,
+                ..*ship
+            },
+            Direction::West => Ship {
+                x: ship.x - distance,
+                ..*ship
+            },
+        },
+        Action::North => Ship {
+            y: ship.y - distance,
+            ..*ship
+        },
+        Action::South => Ship {
+            y: ship.y + distance,
+            ..*ship
+        },
+        Action::East => Ship {
+            x: ship.x + distance,
+            ..*ship
+        },
+        Action::West => Ship {
+            x: ship.x - distance,
+            ..*ship
+        },
+        Action::Left => Ship {
+            direction: ship.direction.turn(distance),
+            ..*ship
+        },
+        Action::Right => Ship {
+            direction: ship.direction.turn(-distance),
+            ..*ship
+        },
+    }
+}
+
+impl Direction {
+    /// turn counterclockwise
+    fn turn(&self, mut distance: f32) -> Direction {
+        while distance < 0.0 {
+            distance += 360.0
+        }
+        while distance >= 360.0 {
+            distance -= 360.0
+        }
+
+        match distance {
+            0.0 => *self,
+            90.0 => match self {
+                Direction::North => Direction::West,
+                Direction::West => Direction::South,
+                Direction::South => Direction::East,
+                Direction::East => Direction::North,
+            },
+            180.0 => match self {
+                Direction::North => Direction::South,
+                Direction::West => Direction::East,
+                Direction::South => Direction::North,
+                Direction::East => Direction::West,
+            },
+            270.0 => self.turn(90.0).turn(180.0),
+            _ => *self,
+        }
+    }
+}
+
+fn navigate_pt2(ship: &Ship, instruction: &Instruction) -> Ship {
+    let distance = instruction.value;
+
+    match instruction.action {
+        Action::Forward => Ship {
+            x: ship.x + ship.waypoint.x * distance,
+            y: ship.y + ship.waypoint.y * distance,
+            ..*ship
+        },
+        Action::North => Ship {
+            waypoint: Vector {
+                y: ship.waypoint.y - distance,
+                ..ship.waypoint
+            },
+            ..*ship
+        },
+        Action::South => Ship {
+            waypoint: Vector {
+                y: ship.waypoint.y + distance,
+                ..ship.waypoint
+            },
+            ..*ship
+        },
+        Action::East => Ship {
+            waypoint: Vector {
+                x: ship.waypoint.x + distance,
+                ..ship.waypoint
+            },
+            ..*ship
+        },
+        Action::West => Ship {
+            waypoint: Vector {
+                x: ship.waypoint.x - distance,
+                ..ship.waypoint
+            },
+            ..*ship
+        },
+        Action::Left => Ship {
+            waypoint: ship.waypoint.turn(-distance),
+            ..*ship
+        },
+        Action::Right => Ship {
+            waypoint: ship.waypoint.turn(distance),
+            ..*ship
+        },
+    }
+}
+
+const START: Ship = Ship {
+    x: 0.0,
+    y: 0.0,
+    direction: Direction::East,
+    waypoint: Vector { x: 10.0, y: -1.0 },
+};
+
+impl Ship {
+    /// Manhattan distance from the origin, rounded to compensate for the
+    /// floating point error accumulated while turning the waypoint.
+    fn distance(&self) -> i64 {
+        (self.x.abs() + self.y.abs()).round() as i64
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_instruction(line).map_err(|why| Error::parse(i + 1, why)))
+            .collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<Answer, Error> {
+        let final_ship = instructions.iter().fold(START, |ship, instruction| {
+            let next = navigate(&ship, instruction);
+            aoc_common::debug!("{:?}", next);
+            next
+        });
+
+        Ok(final_ship.distance().into())
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<Answer, Error> {
+        let final_ship = instructions.iter().fold(START, |ship, instruction| {
+            let next = navigate_pt2(&ship, instruction);
+            aoc_common::debug!("{:?}", next);
+            next
+        });
+
+        Ok(final_ship.distance().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = include_str!("../input_example.txt");
+
+    #[test]
+    fn rejects_unknown_actions() {
+        assert!(parse_instruction("F10").is_ok());
+        assert!(parse_instruction("X10").is_err());
+        assert!(parse_instruction("N").is_err());
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day12>(EXAMPLE, Part::One).unwrap(),
+            Answer::Signed(25)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day12>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Signed(286)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use aoc_common::{Answer, Error, Solution};
+
+/// The earliest departure timestamp and the bus intervals; buses marked
+/// with `x` are `None`.
+pub struct Notes {
+    time: u32,
+    buses: Vec<Option<u32>>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed = Notes;
+
+    fn parse(input: &str) -> Result<Notes, Error> {
+        let mut lines = input.lines();
+        let time_string = lines
+            .next()
+            .ok_or_else(|| Error::parse(1, "missing timestamp"))?;
+        let intervals_string = lines
+            .next()
+            .ok_or_else(|| Error::parse(2, "missing bus intervals"))?;
+
+        let time = time_string
+            .parse::<u32>()
+            .map_err(|why| Error::parse(1, why))?;
+
+        let buses = intervals_string
+            .split(',')
+            .map(|s| match s {
+                "x" => Ok(None),
+                s => s
+                    .parse::<u32>()
+                    .map(Some)
+                    .map_err(|why| Error::parse(2, why)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Notes { time, buses })
+    }
+
+    fn part1(notes: &Notes) -> Result<Answer, Error> {
+        let time = notes.time;
+        let (id, wait_time) = notes
+            .buses
+            .iter()
+            .flatten()
+            .map(|i| (i, (time / i + 1) * i - time))
+            .min_by_key(|(_, diff)| *diff)
+            .ok_or_else(|| Error::NoSolution("no bus in service".to_owned()))?;
+
+        Ok((id * wait_time).into())
+    }
+
+    fn part2(notes: &Notes) -> Result<Answer, Error> {
+        let intervals_and_offsets: Vec<_> = notes
+            .buses
+            .iter()
+            .enumerate()
+            .filter_map(|(i, bus)| bus.map(|interval| (i, interval as usize)))
+            .collect();
+
+        if intervals_and_offsets.is_empty() {
+            return Err(Error::NoSolution("no bus in service".to_owned()));
+        }
+        aoc_common::debug!("{:?}", intervals_and_offsets);
+
+        // find a t such that (t - offset) % interval == 0 for each (offset, interval) in intervals
+        let mut t = intervals_and_offsets[0].1;
+        let mut step = intervals_and_offsets[0].1;
+
+        for (raw_offset, interval) in &intervals_and_offsets[1..] {
+            let offset = interval - (raw_offset % interval);
+
+            while t % interval != offset {
+                t += step;
+            }
+
+            step *= interval;
+        }
+
+        // brute force - takes ages
+        /*
+        let (offset, step) = intervals
+            .iter()
+            .max_by_key(|(_, interval)| interval)
+            .unwrap();
+
+        let t = (1..)
+            .map(|i| step * i - *offset as u64)
+            .skip_while(|t| {
+                intervals
+                    .iter()
+                    .any(|(offset, interval)| (t - *offset as u64) % interval != 0)
+            })
+            .nth(0)
+            .unwrap();
+        */
+
+        Ok(t.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = "939
+7,13,x,x,59,x,31,19";
+
+    #[test]
+    fn parses_notes() {
+        let notes = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(notes.time, 939);
+        assert_eq!(
+            notes.buses,
+            vec![
+                Some(7),
+                Some(13),
+                None,
+                None,
+                Some(59),
+                None,
+                Some(31),
+                Some(19)
+            ]
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day13>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(295)
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        let examples = [
+            ("7,13,x,x,59,x,31,19", 1068781),
+            ("17,x,13,19", 3417),
+            ("67,7,59,61", 754018),
+            ("67,x,7,59,61", 779210),
+            ("67,7,x,59,61", 1261476),
+            ("1789,37,47,1889", 1202161486),
+        ];
+
+        for &(buses, t) in &examples {
+            let input = format!("0\n{}", buses);
+            assert_eq!(
+                solve_part::<Day13>(&input, Part::Two).unwrap(),
+                Answer::Unsigned(t),
+                "{}",
+                buses
+            );
+        }
+    }
+}
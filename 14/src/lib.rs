@@ -0,0 +1,215 @@
+use aoc_common::{Answer, Error, Solution};
+use std::collections::HashMap;
+use std::iter::once;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use thiserror::Error;
+
+pub enum Instruction {
+    Mask(String),
+    Mem { address: usize, value: u64 },
+}
+
+#[derive(Error, Debug)]
+pub enum InstructionError {
+    #[error("wrong 'mask' instruction format")]
+    WrongMask,
+    #[error("wrong 'mem' instruction format")]
+    WrongMem,
+    #[error("unknown instruction")]
+    UnknownInstruction,
+    #[error(transparent)]
+    ParseError(#[from] ParseIntError),
+}
+
+impl FromStr for Instruction {
+    type Err = InstructionError;
+
+    fn from_str(line: &str) -> Result<Instruction, InstructionError> {
+        if line.starts_with("mask = ") {
+            let bits = line
+                .strip_prefix("mask = ")
+                .ok_or(InstructionError::WrongMask)?;
+            Ok(Instruction::Mask(bits.to_owned()))
+        } else if line.starts_with("mem[") {
+            if let (Some(start), Some(end)) = (line.find('['), line.find(']')) {
+                let address: &usize = &line[start + 1..end].parse()?;
+                let value_offset = 3 + line.find(" = ").ok_or(InstructionError::WrongMem)?;
+                let value: u64 = line[value_offset..].parse()?;
+
+                Ok(Instruction::Mem {
+                    address: *address,
+                    value,
+                })
+            } else {
+                Err(InstructionError::WrongMem)
+            }
+        } else {
+            Err(InstructionError::UnknownInstruction)
+        }
+    }
+}
+
+struct State {
+    mask_0: u64,
+    mask_1: u64,
+    memory: HashMap<usize, u64>,
+}
+
+struct State2 {
+    mask_ones: usize,
+    floating_positions: Vec<usize>,
+    memory: HashMap<usize, u64>,
+}
+
+fn floating_positions(mask: &str) -> Vec<usize> {
+    mask.chars()
+        .rev()
+        .enumerate()
+        .filter_map(|(i, c)| if c == 'X' { Some(i) } else { None })
+        .collect()
+}
+
+fn variations(positions: &[usize], address: usize) -> Box<dyn Iterator<Item = usize>> {
+    if let Some((p, ps)) = positions.split_first() {
+        let mask = !(1 << p);
+        let zero = address & mask;
+        let one = address & mask | (1 << p);
+        Box::new(variations(ps, zero).chain(variations(ps, one)))
+    } else {
+        Box::new(once(address))
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| line.parse().map_err(|why| Error::parse(i + 1, why)))
+            .collect()
+    }
+
+    fn part1(program: &Vec<Instruction>) -> Result<Answer, Error> {
+        let mut state = State {
+            mask_0: !0,
+            mask_1: 0,
+            memory: HashMap::new(),
+        };
+
+        for instruction in program {
+            match instruction {
+                Instruction::Mask(bits) => {
+                    state.mask_1 = bits.chars().fold(0, |n, c| {
+                        (n << 1)
+                            | match c {
+                                '1' => 1,
+                                _ => 0,
+                            }
+                    });
+                    state.mask_0 = bits.chars().fold(!0, |n, c| {
+                        (n << 1)
+                            | match c {
+                                '0' => 0,
+                                _ => 1,
+                            }
+                    });
+                }
+                Instruction::Mem { address, value } => {
+                    state
+                        .memory
+                        .insert(*address, value & state.mask_0 | state.mask_1);
+                }
+            }
+        }
+
+        let sum: u64 = state.memory.values().sum();
+        Ok(sum.into())
+    }
+
+    fn part2(program: &Vec<Instruction>) -> Result<Answer, Error> {
+        let mut state = State2 {
+            mask_ones: 0,
+            floating_positions: vec![],
+            memory: HashMap::new(),
+        };
+
+        for instruction in program {
+            match instruction {
+                Instruction::Mask(bits) => {
+                    state.mask_ones = bits.chars().fold(0, |n, c| {
+                        (n << 1)
+                            | match c {
+                                '1' => 1,
+                                _ => 0,
+                            }
+                    });
+                    state.floating_positions = floating_positions(bits);
+                }
+                Instruction::Mem { address, value } => {
+                    for addr in variations(&state.floating_positions, address | state.mask_ones) {
+                        state.memory.insert(addr, *value);
+                    }
+                }
+            }
+        }
+
+        let sum: u64 = state.memory.values().sum();
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE_1: &str = include_str!("../input_example.txt");
+    // the first example has too many floating bits to be run with part II
+    const EXAMPLE_2: &str = include_str!("../input_example_2.txt");
+
+    #[test]
+    fn parses_instructions() {
+        assert!(matches!(
+            "mem[8] = 11".parse::<Instruction>(),
+            Ok(Instruction::Mem {
+                address: 8,
+                value: 11
+            })
+        ));
+        assert!(matches!(
+            "mask = X1X".parse::<Instruction>(),
+            Ok(Instruction::Mask(bits)) if bits == "X1X"
+        ));
+        assert!(matches!(
+            "mem[8 = 11".parse::<Instruction>(),
+            Err(InstructionError::WrongMem)
+        ));
+        assert!(matches!(
+            "jmp 4".parse::<Instruction>(),
+            Err(InstructionError::UnknownInstruction)
+        ));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day14>(EXAMPLE_1, Part::One).unwrap(),
+            Answer::Unsigned(165)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day14>(EXAMPLE_2, Part::Two).unwrap(),
+            Answer::Unsigned(208)
+        );
+    }
+}
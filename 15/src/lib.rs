@@ -0,0 +1,125 @@
+use aoc_common::{Answer, Error, Solution};
+use std::collections::HashMap;
+
+struct Game {
+    most_recent_turn: HashMap<usize, usize>,
+    turn: usize,
+    last: usize,
+}
+
+impl Game {
+    fn new() -> Game {
+        Game {
+            most_recent_turn: HashMap::new(),
+            turn: 0,
+            last: 0,
+        }
+    }
+
+    fn add(&mut self, n: usize) {
+        self.most_recent_turn.insert(self.last, self.turn);
+        self.turn += 1;
+        self.last = n;
+    }
+
+    fn play(&mut self) {
+        let n = self
+            .most_recent_turn
+            .get(&self.last)
+            .map(|n| self.turn - n)
+            .unwrap_or(0);
+        self.add(n);
+    }
+}
+
+fn next_turn(turns: &[usize]) -> usize {
+    if let Some((last, rest)) = turns.split_last() {
+        // find the last turn this number was announced
+        rest.iter()
+            .rev()
+            .enumerate()
+            .find_map(|(i, n)| if n == last { Some(i + 1) } else { None })
+            .unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, Error> {
+        input
+            .trim()
+            .split(',')
+            .map(|s| s.parse::<usize>().map_err(|why| Error::parse(1, why)))
+            .collect()
+    }
+
+    fn part1(starting_numbers: &Vec<usize>) -> Result<Answer, Error> {
+        let final_turn = 2020;
+        let mut turns = Vec::with_capacity(final_turn);
+        turns.extend(starting_numbers);
+
+        for _ in turns.len()..final_turn {
+            turns.push(next_turn(&turns));
+        }
+
+        Ok((*turns.last().unwrap()).into())
+    }
+
+    fn part2(starting_numbers: &Vec<usize>) -> Result<Answer, Error> {
+        let final_turn = 30000000;
+        let mut game = Game::new();
+
+        for &n in starting_numbers {
+            game.add(n);
+        }
+
+        for _ in game.turn..final_turn {
+            game.play();
+        }
+
+        Ok(game.last.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    #[test]
+    fn part1_examples() {
+        let examples = [
+            ("0,3,6", 436),
+            ("1,3,2", 1),
+            ("2,1,3", 10),
+            ("1,2,3", 27),
+            ("2,3,1", 78),
+            ("3,2,1", 438),
+            ("3,1,2", 1836),
+        ];
+
+        for &(input, n) in &examples {
+            assert_eq!(
+                solve_part::<Day15>(input, Part::One).unwrap(),
+                Answer::Unsigned(n),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day15>("0,3,6\n", Part::Two).unwrap(),
+            Answer::Unsigned(175594)
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use aoc_common::{Answer, Error, Solution};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+#[derive(Debug)]
+pub struct Field {
+    name: String,
+    ranges: Vec<RangeInclusive<usize>>,
+}
+
+fn parse_field(description: &str) -> Option<Field> {
+    let mut segments = description.split(": ");
+
+    let name = segments.next()?.to_owned();
+
+    let ranges = segments
+        .next()?
+        .split(" or ")
+        .filter_map(|s| {
+            let mut limits = s.split('-');
+            let start = limits.next()?.parse::<usize>().ok()?;
+            let end = limits.next()?.parse::<usize>().ok()?;
+            Some(start..=end)
+        })
+        .collect::<Vec<_>>();
+
+    Some(Field { name, ranges })
+}
+
+fn parse_ticket(line: &str) -> Vec<usize> {
+    line.split(',').filter_map(|s| s.parse().ok()).collect()
+}
+
+pub struct Notes {
+    fields: Vec<Field>,
+    my_ticket: Vec<usize>,
+    nearby_tickets: Vec<Vec<usize>>,
+}
+
+fn value_is_valid(fields: &[Field], value: &usize) -> bool {
+    fields
+        .iter()
+        .flat_map(|f| &f.ranges)
+        .any(|r| r.contains(value))
+}
+
+fn ticket_is_valid(fields: &[Field], ticket: &[usize]) -> bool {
+    ticket.iter().all(|value| value_is_valid(fields, value))
+}
+
+/// Determine which position on the tickets belongs to which field.
+fn field_positions(notes: &Notes) -> HashMap<&str, usize> {
+    let Notes {
+        fields,
+        my_ticket,
+        nearby_tickets,
+    } = notes;
+
+    let mut valid_tickets: Vec<&Vec<usize>> = nearby_tickets
+        .iter()
+        .filter(|t| ticket_is_valid(fields, t))
+        .collect();
+
+    valid_tickets.push(my_ticket);
+
+    // collect values for each position in the tickets
+    let mut values_by_field_index: Vec<HashSet<&usize>> = Vec::with_capacity(my_ticket.len());
+    for i in 0..my_ticket.len() {
+        let values = valid_tickets.iter().map(|t| &t[i]);
+        values_by_field_index.push(values.collect());
+    }
+
+    let mut indices_by_field: HashMap<&str, HashSet<usize>> = fields
+        .iter()
+        .map(|f| {
+            (
+                &f.name[..],
+                (0..values_by_field_index.len()).collect::<HashSet<_>>(),
+            )
+        })
+        .collect();
+
+    let fields_by_name: HashMap<&str, &Field> = fields.iter().map(|f| (&f.name[..], f)).collect();
+
+    // first round - filter indices whose values are outside the field's valid ranges
+    for (&name, indices) in indices_by_field.iter_mut() {
+        let matching_indices = indices.iter().filter_map(|i| {
+            if values_by_field_index.get(*i).unwrap().iter().all(|v| {
+                fields_by_name
+                    .get(name)
+                    .unwrap()
+                    .ranges
+                    .iter()
+                    .any(|r| r.contains(v))
+            }) {
+                Some(*i)
+            } else {
+                None
+            }
+        });
+
+        *indices = matching_indices.collect();
+    }
+
+    let mut field_index_map = HashMap::<&str, usize>::new();
+
+    while let Some((name, indices)) = indices_by_field.iter().find(|(_, v)| v.len() == 1) {
+        let i = *indices.iter().next().unwrap();
+        field_index_map.insert(name, i);
+        for values in indices_by_field.values_mut() {
+            values.remove(&i);
+        }
+    }
+
+    field_index_map
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed = Notes;
+
+    fn parse(input: &str) -> Result<Notes, Error> {
+        // sections are separated by empty lines
+        let mut sections = input.split("\n\n");
+        let missing = |section| Error::InvalidInput(format!("missing section: {}", section));
+        let fields_section = sections.next().ok_or_else(|| missing("fields"))?;
+        let my_ticket_section = sections.next().ok_or_else(|| missing("your ticket"))?;
+        let nearby_tickets_section = sections.next().ok_or_else(|| missing("nearby tickets"))?;
+
+        let fields = fields_section.lines().filter_map(parse_field).collect();
+
+        let my_ticket = my_ticket_section
+            .lines()
+            .nth(1)
+            .map(parse_ticket)
+            .ok_or_else(|| missing("your ticket"))?;
+
+        let nearby_tickets = nearby_tickets_section
+            .lines()
+            // skip section header()
+            .skip(1)
+            .map(parse_ticket)
+            .collect();
+
+        Ok(Notes {
+            fields,
+            my_ticket,
+            nearby_tickets,
+        })
+    }
+
+    fn part1(notes: &Notes) -> Result<Answer, Error> {
+        let Notes {
+            fields,
+            nearby_tickets,
+            ..
+        } = notes;
+
+        let error_rate: usize = nearby_tickets
+            .iter()
+            .flatten()
+            .filter(|&value| !value_is_valid(fields, value))
+            .sum();
+
+        Ok(error_rate.into())
+    }
+
+    fn part2(notes: &Notes) -> Result<Answer, Error> {
+        let field_index_map = field_positions(notes);
+        aoc_common::debug!("{:?}", field_index_map);
+
+        let p: usize = field_index_map
+            .iter()
+            .filter(|(name, _)| name.starts_with("departure"))
+            .map(|(_, index)| notes.my_ticket[*index])
+            .product();
+
+        Ok(p.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE_1: &str = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
+
+    const EXAMPLE_2: &str = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+
+    #[test]
+    fn parses_notes() {
+        let notes = Day16::parse(EXAMPLE_1).unwrap();
+        assert_eq!(notes.fields.len(), 3);
+        assert_eq!(notes.fields[1].name, "row");
+        assert_eq!(notes.fields[1].ranges, vec![6..=11, 33..=44]);
+        assert_eq!(notes.my_ticket, vec![7, 1, 14]);
+        assert_eq!(notes.nearby_tickets.len(), 4);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day16>(EXAMPLE_1, Part::One).unwrap(),
+            Answer::Unsigned(71)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let notes = Day16::parse(EXAMPLE_2).unwrap();
+        let positions = field_positions(&notes);
+        assert_eq!(positions.get("row"), Some(&0));
+        assert_eq!(positions.get("class"), Some(&1));
+        assert_eq!(positions.get("seat"), Some(&2));
+
+        // the example has no departure fields, so the product is empty
+        assert_eq!(
+            solve_part::<Day16>(EXAMPLE_2, Part::Two).unwrap(),
+            Answer::Unsigned(1)
+        );
+    }
+}
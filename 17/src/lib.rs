@@ -0,0 +1,210 @@
+use aoc_common::{Answer, Error, Solution};
+use std::convert::TryFrom;
+use std::hash::Hash;
+use std::{
+    collections::{HashMap, HashSet},
+    vec::IntoIter,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Cell3D {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+struct Cell4D {
+    w: i32,
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+enum Liveliness {
+    Alive,
+    Dead,
+}
+
+fn liveliness<P: Hash + Eq>(cells: &HashSet<P>, cell: &P) -> Liveliness {
+    if cells.contains(cell) {
+        Liveliness::Alive
+    } else {
+        Liveliness::Dead
+    }
+}
+
+fn next_state(l: Liveliness, neighbors: usize) -> Liveliness {
+    match l {
+        Liveliness::Alive => match neighbors {
+            2 | 3 => Liveliness::Alive,
+            _ => Liveliness::Dead,
+        },
+        Liveliness::Dead => match neighbors {
+            3 => Liveliness::Alive,
+            _ => Liveliness::Dead,
+        },
+    }
+}
+
+fn step<C: Hash + Eq>(cells: &HashSet<C>, env: &dyn Fn(&C) -> IntoIter<C>) -> HashSet<C> {
+    cells
+        .iter()
+        .flat_map(env)
+        .fold(HashMap::new(), |mut liveliness, cell| {
+            *liveliness.entry(cell).or_insert(0) += 1;
+            liveliness
+        })
+        .into_iter()
+        .filter(
+            |(p, neighbors)| match next_state(liveliness(cells, p), *neighbors) {
+                Liveliness::Alive => true,
+                Liveliness::Dead => false,
+            },
+        )
+        .map(|(p, _)| p)
+        .collect::<HashSet<C>>()
+}
+
+fn part_1(initial_cells: &[Cell3D]) -> usize {
+    let cells: HashSet<Cell3D> = initial_cells.iter().copied().collect();
+
+    let gen_1 = step::<Cell3D>(&cells, &env3);
+    let gen_2 = step::<Cell3D>(&gen_1, &env3);
+    let gen_3 = step::<Cell3D>(&gen_2, &env3);
+    let gen_4 = step::<Cell3D>(&gen_3, &env3);
+    let gen_5 = step::<Cell3D>(&gen_4, &env3);
+    let gen_6 = step::<Cell3D>(&gen_5, &env3);
+    //println!("{:#?}", env_xyz(&Point { x: 0, y: 0, z: 0 }))j
+
+    gen_6.len()
+}
+
+fn part_2(initial_cells: &[Cell3D]) -> usize {
+    let cells: HashSet<Cell4D> = initial_cells
+        .iter()
+        .map(|&Cell3D { x, y, z }| Cell4D { w: 0, x, y, z })
+        .collect();
+
+    let gen_1 = step::<Cell4D>(&cells, &env4);
+    let gen_2 = step::<Cell4D>(&gen_1, &env4);
+    let gen_3 = step::<Cell4D>(&gen_2, &env4);
+    let gen_4 = step::<Cell4D>(&gen_3, &env4);
+    let gen_5 = step::<Cell4D>(&gen_4, &env4);
+    let gen_6 = step::<Cell4D>(&gen_5, &env4);
+    //println!("{:#?}", env_xyz(&Point { x: 0, y: 0, z: 0 }))j
+
+    gen_6.len()
+}
+
+fn env3(p: &Cell3D) -> IntoIter<Cell3D> {
+    (-1..=1)
+        .flat_map(move |x: i32| {
+            (-1..=1).flat_map(move |y: i32| (-1..=1).map(move |z: i32| (x, y, z)))
+        })
+        .filter(|(x, y, z)| {
+            vec![x.abs(), y.abs(), z.abs()]
+                .into_iter()
+                .max()
+                .unwrap_or(0)
+                == 1
+        })
+        .map(move |(x, y, z)| Cell3D {
+            x: p.x + x,
+            y: p.y + y,
+            z: p.z + z,
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+fn env4(c: &Cell4D) -> IntoIter<Cell4D> {
+    (-1..=1)
+        .flat_map(move |w: i32| {
+            (-1..=1).flat_map(move |x: i32| {
+                (-1..=1).flat_map(move |y: i32| (-1..=1).map(move |z: i32| (w, x, y, z)))
+            })
+        })
+        .filter(|(w, x, y, z)| {
+            vec![w.abs(), x.abs(), y.abs(), z.abs()]
+                .into_iter()
+                .max()
+                .unwrap_or(0)
+                == 1
+        })
+        .map(move |(w, x, y, z)| Cell4D {
+            w: c.w + w,
+            x: c.x + x,
+            y: c.y + y,
+            z: c.z + z,
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    /// active cells of the initial slice at z = 0
+    type Parsed = Vec<Cell3D>;
+
+    fn parse(input: &str) -> Result<Vec<Cell3D>, Error> {
+        let mut cells = Vec::new();
+        for (y, l) in input.lines().enumerate() {
+            let line_no = y + 1;
+            let y = i32::try_from(y).map_err(|why| Error::parse(line_no, why))?;
+            for (x, c) in l.chars().enumerate() {
+                let x = i32::try_from(x).map_err(|why| Error::parse(line_no, why))?;
+                match c {
+                    '#' => cells.push(Cell3D { x, y, z: 0 }),
+                    '.' => (),
+                    _ => return Err(Error::parse(line_no, format!("invalid cell '{}'", c))),
+                }
+            }
+        }
+
+        Ok(cells)
+    }
+
+    fn part1(cells: &Vec<Cell3D>) -> Result<Answer, Error> {
+        Ok(part_1(cells).into())
+    }
+
+    fn part2(cells: &Vec<Cell3D>) -> Result<Answer, Error> {
+        Ok(part_2(cells).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    const EXAMPLE: &str = include_str!("../input_example.txt");
+
+    #[test]
+    fn parses_initial_slice() {
+        let cells = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(cells.len(), 5);
+        assert!(cells.contains(&Cell3D { x: 1, y: 0, z: 0 }));
+        assert!(Day17::parse(".#.\n.?.").is_err());
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day17>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(112)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day17>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(848)
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::{Answer, Error, Solution};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> Result<Answer, Error> {
+        Err(Error::NotImplemented)
+    }
+
+    fn part2(_lines: &Vec<String>) -> Result<Answer, Error> {
+        Err(Error::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{solve_part, Part};
+
+    // the example from the puzzle description
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part1_example() {
+        assert_eq!(
+            solve_part::<Day00>(EXAMPLE, Part::One).unwrap(),
+            Answer::Unsigned(0)
+        );
+    }
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part2_example() {
+        assert_eq!(
+            solve_part::<Day00>(EXAMPLE, Part::Two).unwrap(),
+            Answer::Unsigned(0)
+        );
+    }
+}
@@ -1,32 +1,5 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-fn read_input(filename: &str) -> String {
-    let path = Path::new(filename);
-    let display = path.display();
-
-    let mut file = match File::open(&path) {
-        Err(why) => panic!("couldn't open {}: {}", display, why),
-        Ok(file) => file,
-    };
-
-    let mut input = String::new();
-    match file.read_to_string(&mut input) {
-        Err(why) => panic!("couldn't read {}: {}", display, why),
-        Ok(_) => (),
-    }
-
-    input
-}
+use aoc_2020_template::Day00;
 
 fn main() {
-    let input = read_input("input.txt");
-    println!("{}", input);
-
-    println!("--- part I ------------------------------------------");
-    println!("TODO");
-
-    println!("--- part II -----------------------------------------");
-    println!("TODO");
+    aoc_common::run::<Day00>();
 }
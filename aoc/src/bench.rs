@@ -0,0 +1,270 @@
+//! Benchmarks of every day's parsing and parts.
+//!
+//! The report is tab-separated with one row per day and stage:
+//!
+//! ```text
+//! day  stage  runs  min_ns  median_ns  mean_ns
+//! ```
+//!
+//! A previous report can be passed as `--baseline`. The new report then
+//! adds each stage's baseline median and how much the median changed.
+
+use crate::{day_dir, days, parse_day};
+use aoc_common::{Error, Input, Samples, Stage};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_RUNS: usize = 10;
+
+pub struct BenchOptions {
+    days: Vec<u8>,
+    runs: usize,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+}
+
+pub fn parse_options(args: &[String]) -> Result<BenchOptions, Error> {
+    let mut options = BenchOptions {
+        days: days::DAYS.to_vec(),
+        runs: DEFAULT_RUNS,
+        output: None,
+        baseline: None,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::InvalidArgument(format!("{} needs a value", arg)))
+        };
+
+        match arg.as_str() {
+            "--runs" => {
+                options.runs = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or_else(|| {
+                        Error::InvalidArgument("--runs needs a positive number".to_owned())
+                    })?;
+            }
+            "--output" => options.output = Some(PathBuf::from(value()?)),
+            "--baseline" => options.baseline = Some(PathBuf::from(value()?)),
+            "all" => options.days = days::DAYS.to_vec(),
+            day => options.days = vec![parse_day(day)?],
+        }
+    }
+
+    Ok(options)
+}
+
+/// Summary of the samples of one stage.
+struct Row {
+    day: u8,
+    stage: Stage,
+    runs: usize,
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+}
+
+impl Row {
+    fn new(day: u8, samples: &Samples) -> Row {
+        let mut durations = samples.durations.clone();
+        durations.sort();
+
+        let runs = durations.len();
+        let total: Duration = durations.iter().sum();
+
+        Row {
+            day,
+            stage: samples.stage,
+            runs,
+            min: durations[0],
+            median: durations[runs / 2],
+            mean: total / runs as u32,
+        }
+    }
+}
+
+/// Median in nanoseconds of each (day, stage) of a previous report.
+fn read_baseline(text: &str) -> Result<HashMap<(u8, String), u128>, Error> {
+    let mut lines = text.lines();
+    let header: Vec<&str> = lines
+        .next()
+        .ok_or_else(|| Error::InvalidInput("empty baseline report".to_owned()))?
+        .split('\t')
+        .collect();
+    let column = |name| {
+        header
+            .iter()
+            .position(|&c| c == name)
+            .ok_or_else(|| Error::InvalidInput(format!("baseline report has no '{}' column", name)))
+    };
+    let (day, stage, median) = (column("day")?, column("stage")?, column("median_ns")?);
+
+    lines
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let field = |n: usize| {
+                fields
+                    .get(n)
+                    .ok_or_else(|| Error::parse(i + 2, "missing column"))
+            };
+
+            Ok((
+                (
+                    field(day)?
+                        .parse()
+                        .map_err(|why| Error::parse(i + 2, why))?,
+                    field(stage)?.to_string(),
+                ),
+                field(median)?
+                    .parse()
+                    .map_err(|why| Error::parse(i + 2, why))?,
+            ))
+        })
+        .collect()
+}
+
+fn render(rows: &[Row], baseline: Option<&HashMap<(u8, String), u128>>) -> String {
+    let mut report = String::from("day\tstage\truns\tmin_ns\tmedian_ns\tmean_ns");
+    if baseline.is_some() {
+        report.push_str("\tbaseline_median_ns\tchange");
+    }
+    report.push('\n');
+
+    for row in rows {
+        report.push_str(&format!(
+            "{:02}\t{}\t{}\t{}\t{}\t{}",
+            row.day,
+            row.stage,
+            row.runs,
+            row.min.as_nanos(),
+            row.median.as_nanos(),
+            row.mean.as_nanos()
+        ));
+
+        if let Some(baseline) = baseline {
+            match baseline.get(&(row.day, row.stage.to_string())) {
+                Some(&before) if before > 0 => {
+                    let change = (row.median.as_nanos() as f64 / before as f64 - 1.0) * 100.0;
+                    report.push_str(&format!("\t{}\t{:+.1}%", before, change));
+                }
+                _ => report.push_str("\t-\t-"),
+            }
+        }
+        report.push('\n');
+    }
+
+    report
+}
+
+/// Benchmark the selected days and write the report; returns whether all
+/// days could be benchmarked.
+pub fn bench(options: &BenchOptions) -> bool {
+    let baseline = match &options.baseline {
+        Some(path) => match Input::File(path.clone())
+            .load()
+            .map_err(Error::from)
+            .and_then(|text| read_baseline(&text))
+        {
+            Ok(baseline) => Some(baseline),
+            Err(why) => {
+                eprintln!("baseline {}: {}", path.display(), why);
+                return false;
+            }
+        },
+        None => None,
+    };
+
+    let mut success = true;
+    let mut rows = Vec::new();
+
+    for &day in &options.days {
+        let input = match Input::File(day_dir(day).join("input.txt")).load() {
+            Ok(input) => input,
+            Err(why) => {
+                eprintln!("day {:02}: {}", day, why);
+                success = false;
+                continue;
+            }
+        };
+
+        match days::bench(day, &input, options.runs) {
+            Some(Ok(samples)) => rows.extend(samples.iter().map(|s| Row::new(day, s))),
+            Some(Err(why)) => {
+                eprintln!("day {:02}: {}", day, why);
+                success = false;
+            }
+            None => unreachable!("day {} has no solution", day),
+        }
+    }
+
+    let report = render(&rows, baseline.as_ref());
+    match &options.output {
+        Some(path) => {
+            if let Err(why) = fs::write(path, report) {
+                eprintln!("couldn't write {}: {}", path.display(), why);
+                success = false;
+            }
+        }
+        None => print!("{}", report),
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Part;
+
+    #[test]
+    fn summarizes_samples() {
+        let samples = Samples {
+            stage: Stage::Parse,
+            durations: vec![3, 1, 8]
+                .into_iter()
+                .map(Duration::from_nanos)
+                .collect(),
+        };
+        let row = Row::new(9, &samples);
+
+        assert_eq!(row.runs, 3);
+        assert_eq!(row.min, Duration::from_nanos(1));
+        assert_eq!(row.median, Duration::from_nanos(3));
+        assert_eq!(row.mean, Duration::from_nanos(4));
+    }
+
+    #[test]
+    fn compares_with_baseline() {
+        let rows = [Row {
+            day: 9,
+            stage: Stage::Part(Part::Two),
+            runs: 1,
+            min: Duration::from_nanos(150),
+            median: Duration::from_nanos(150),
+            mean: Duration::from_nanos(150),
+        }];
+        let baseline = read_baseline(
+            "day\tstage\truns\tmin_ns\tmedian_ns\tmean_ns\n09\tpart2\t1\t90\t100\t110\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            render(&rows, Some(&baseline)),
+            "day\tstage\truns\tmin_ns\tmedian_ns\tmean_ns\tbaseline_median_ns\tchange\n\
+             09\tpart2\t1\t150\t150\t150\t100\t+50.0%\n"
+        );
+    }
+
+    #[test]
+    fn rejects_baseline_without_medians() {
+        assert!(read_baseline("day\tstage\n09\tparse\n").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{day_dir, days, load_input, RunOptions};
+use aoc_common::{Error, KnownAnswers, PartReport, Verdict};
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+}
+
+/// Solve the selected days and compare the answers with the days'
+/// `answers.toml`; returns whether no part failed.
+pub fn check(options: &RunOptions) -> bool {
+    let mut tally = Tally::default();
+
+    for &day in &options.days {
+        let fail_day = |tally: &mut Tally, why: &dyn std::fmt::Display| {
+            println!("day {:02}: FAIL ({})", day, why);
+            tally.failed += options.parts.len();
+        };
+
+        let known = match KnownAnswers::load(day_dir(day).join("answers.toml")) {
+            Ok(known) => known,
+            Err(why) => {
+                fail_day(&mut tally, &why);
+                continue;
+            }
+        };
+
+        let input = match load_input(options, day) {
+            Ok(input) => input,
+            Err(why) => {
+                fail_day(&mut tally, &why);
+                continue;
+            }
+        };
+
+        let report = match days::solve(day, &input, &options.parts, options.parsing) {
+            Some(Ok(report)) => report,
+            Some(Err(why)) => {
+                fail_day(&mut tally, &why);
+                continue;
+            }
+            None => unreachable!("day {} has no solution", day),
+        };
+        if !report.rejected.is_empty() {
+            eprint!(
+                "day {:02}: {}",
+                day,
+                aoc_common::rejected_summary(&report.rejected)
+            );
+        }
+
+        for PartReport { part, answer, .. } in report.parts {
+            let verdict = match answer {
+                Ok(answer) => match known.check(part, &answer) {
+                    Verdict::Pass => {
+                        tally.passed += 1;
+                        format!("pass ({})", answer)
+                    }
+                    Verdict::Fail { expected } => {
+                        tally.failed += 1;
+                        format!("FAIL (expected {}, got {})", expected, answer)
+                    }
+                    Verdict::Unknown => {
+                        tally.unknown += 1;
+                        format!("unknown ({})", answer)
+                    }
+                },
+                Err(Error::NotImplemented) => {
+                    tally.unknown += 1;
+                    "unknown (not implemented yet)".to_owned()
+                }
+                Err(why) => {
+                    tally.failed += 1;
+                    format!("FAIL ({})", why)
+                }
+            };
+
+            println!("day {:02} part {}: {}", day, part, verdict);
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} unknown",
+        tally.passed, tally.failed, tally.unknown
+    );
+
+    tally.failed == 0
+}
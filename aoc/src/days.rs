@@ -0,0 +1,57 @@
+use aoc_common::{Error, Parsing, Part, Report, Samples, Solution};
+
+macro_rules! days {
+    ($($solution:ty),* $(,)?) => {
+        /// every day that has a solution, in calendar order
+        pub const DAYS: &[u8] = &[$(<$solution as Solution>::DAY),*];
+
+        /// Parse the input and solve the given parts of a day; `None` if
+        /// there's no solution for that day.
+        pub fn solve(
+            day: u8,
+            input: &str,
+            parts: &[Part],
+            parsing: Parsing,
+        ) -> Option<Result<Report, Error>> {
+            $(
+                if day == <$solution as Solution>::DAY {
+                    return Some(aoc_common::solve::<$solution>(input, parts, parsing));
+                }
+            )*
+
+            None
+        }
+
+        /// Time each stage of a day over `runs` runs; `None` if there's no
+        /// solution for that day.
+        pub fn bench(day: u8, input: &str, runs: usize) -> Option<Result<Vec<Samples>, Error>> {
+            $(
+                if day == <$solution as Solution>::DAY {
+                    return Some(aoc_common::bench::<$solution>(input, runs));
+                }
+            )*
+
+            None
+        }
+    };
+}
+
+days! {
+    aoc_2020_01::Day01,
+    aoc_2020_02::Day02,
+    aoc_2020_03::Day03,
+    aoc_2020_04::Day04,
+    aoc_2020_05::Day05,
+    aoc_2020_06::Day06,
+    aoc_2020_07::Day07,
+    aoc_2020_08::Day08,
+    aoc_2020_09::Day09,
+    aoc_2020_10::Day10,
+    aoc_2020_11::Day11,
+    aoc_2020_12::Day12,
+    aoc_2020_13::Day13,
+    aoc_2020_14::Day14,
+    aoc_2020_15::Day15,
+    aoc_2020_16::Day16,
+    aoc_2020_17::Day17,
+}
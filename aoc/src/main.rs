@@ -0,0 +1,268 @@
+//! Runs the solutions of every day from a single binary.
+//!
+//! ```text
+//! aoc run [DAY] [--part 1|2] [--input PATH] [--strict] [--time] [--format text|json] [-v|-vv]
+//! aoc check [DAY] [--part 1|2] [--strict] [-v|-vv]
+//! aoc bench [DAY] [--runs N] [--output PATH] [--baseline PATH]
+//! aoc new DAY
+//! ```
+//!
+//! Without a day, all days are solved with their own `input.txt`. `--input -`
+//! reads the input from stdin. Malformed lines that a day can skip are
+//! listed on stderr; `--strict` fails the day on them instead. `--time` also
+//! prints how long parsing and each part took. `--format json` prints one
+//! line of JSON per day with the answers, timings and skipped lines
+//! instead. `-v` turns on debug output on stderr, `-vv` also prints the
+//! input.
+//!
+//! `check` compares the answers with the known-good ones in each day's
+//! `answers.toml` and reports every part as pass, fail or unknown.
+//!
+//! `bench` times parsing and each part over repeated runs and writes a
+//! tab-separated report; see the `bench` module.
+//!
+//! `new` creates the crate for a new day from `_template`; see the
+//! `scaffold` module.
+
+use aoc_common::{Error, Format, Input, Parsing, Part, PartReport};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod bench;
+mod check;
+mod days;
+mod scaffold;
+
+const USAGE: &str =
+    "usage: aoc run [DAY] [--part 1|2] [--input PATH] [--strict] [--time] [--format text|json] [-v|-vv]
+       aoc check [DAY] [--part 1|2] [--strict] [-v|-vv]
+       aoc bench [DAY] [--runs N] [--output PATH] [--baseline PATH]
+       aoc new DAY";
+
+struct RunOptions {
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    input: Option<Input>,
+    parsing: Parsing,
+    time: bool,
+    format: Format,
+    verbosity: u8,
+}
+
+/// The workspace root, one level above this crate.
+fn workspace() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_owned()
+}
+
+/// The day's directory in the workspace.
+fn day_dir(day: u8) -> PathBuf {
+    workspace().join(format!("{:02}", day))
+}
+
+/// The input given with `--input`, or else the `input.txt` in the day's
+/// directory.
+fn load_input(options: &RunOptions, day: u8) -> Result<String, Error> {
+    let input = match &options.input {
+        Some(input) => input.clone(),
+        None => Input::File(day_dir(day).join("input.txt")),
+    };
+
+    Ok(input.load()?)
+}
+
+fn parse_day(arg: &str) -> Result<u8, Error> {
+    arg.parse::<u8>()
+        .ok()
+        .filter(|day| days::DAYS.contains(day))
+        .ok_or_else(|| Error::InvalidArgument(format!("unknown day '{}'", arg)))
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, Error> {
+    let mut options = RunOptions {
+        days: days::DAYS.to_vec(),
+        parts: Part::ALL.to_vec(),
+        input: None,
+        parsing: Parsing::Lenient,
+        time: false,
+        format: Format::Text,
+        verbosity: 0,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args
+                    .next()
+                    .ok_or_else(|| Error::InvalidArgument("--part needs a value".to_owned()))?;
+                options.parts = vec![part.parse()?];
+            }
+            "--input" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| Error::InvalidArgument("--input needs a value".to_owned()))?;
+                options.input = Some(match path.as_str() {
+                    "-" => Input::Stdin,
+                    path => Input::File(PathBuf::from(path)),
+                });
+            }
+            "--strict" => options.parsing = Parsing::Strict,
+            "--time" => options.time = true,
+            "--format" => {
+                let format = args
+                    .next()
+                    .ok_or_else(|| Error::InvalidArgument("--format needs a value".to_owned()))?;
+                options.format = format.parse()?;
+            }
+            "-v" | "--verbose" => options.verbosity += 1,
+            "-vv" => options.verbosity += 2,
+            "all" => options.days = days::DAYS.to_vec(),
+            day => options.days = vec![parse_day(day)?],
+        }
+    }
+
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err(Error::InvalidArgument(
+            "--input needs a single day".to_owned(),
+        ));
+    }
+
+    Ok(options)
+}
+
+/// Solve the selected days and parts; returns whether all of them succeeded.
+fn run(options: &RunOptions) -> bool {
+    let mut success = true;
+
+    for &day in &options.days {
+        let input = match load_input(options, day) {
+            Ok(input) => input,
+            Err(why) => {
+                eprintln!("day {:02}: {}", day, why);
+                success = false;
+                continue;
+            }
+        };
+        if options.verbosity > 1 {
+            eprintln!("{}", input);
+        }
+
+        let report = match days::solve(day, &input, &options.parts, options.parsing) {
+            Some(Ok(report)) => report,
+            Some(Err(why)) => {
+                eprintln!("day {:02}: {}", day, why);
+                success = false;
+                continue;
+            }
+            None => unreachable!("day {} has no solution", day),
+        };
+        if options.format == Format::Text && !report.rejected.is_empty() {
+            eprint!(
+                "day {:02}: {}",
+                day,
+                aoc_common::rejected_summary(&report.rejected)
+            );
+        }
+
+        if options.format == Format::Json {
+            println!("{}", aoc_common::to_json(&report));
+            success &= report.parts.iter().all(|part| match part.answer {
+                Ok(_) | Err(Error::NotImplemented) => true,
+                Err(_) => false,
+            });
+            continue;
+        }
+
+        if options.time {
+            println!("day {:02} parse: {:?}", day, report.parse_time);
+        }
+
+        for PartReport { part, answer, time } in report.parts {
+            match answer {
+                Ok(answer) => println!("day {:02} part {}: {}", day, part, answer),
+                Err(Error::NotImplemented) => {
+                    println!("day {:02} part {}: not implemented yet", day, part)
+                }
+                Err(why) => {
+                    eprintln!("day {:02} part {}: {}", day, part, why);
+                    success = false;
+                }
+            }
+
+            if options.time {
+                println!("day {:02} part {} time: {:?}", day, part, time);
+            }
+        }
+    }
+
+    success
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let success = match args.split_first() {
+        Some((command, rest)) if command == "run" => match parse_run_options(rest) {
+            Ok(options) => {
+                aoc_common::set_verbosity(options.verbosity);
+                run(&options)
+            }
+            Err(why) => {
+                eprintln!("{}\n{}", why, USAGE);
+                false
+            }
+        },
+        Some((command, rest)) if command == "check" => match parse_run_options(rest) {
+            Ok(options)
+                if options.input.is_none() && !options.time && options.format == Format::Text =>
+            {
+                aoc_common::set_verbosity(options.verbosity);
+                check::check(&options)
+            }
+            Ok(_) => {
+                eprintln!("check only takes a day, --part, --strict and -v\n{}", USAGE);
+                false
+            }
+            Err(why) => {
+                eprintln!("{}\n{}", why, USAGE);
+                false
+            }
+        },
+        Some((command, rest)) if command == "bench" => match bench::parse_options(rest) {
+            Ok(options) => bench::bench(&options),
+            Err(why) => {
+                eprintln!("{}\n{}", why, USAGE);
+                false
+            }
+        },
+        Some((command, [day])) if command == "new" => {
+            let created = day
+                .parse::<u8>()
+                .map_err(|why| format!("invalid day '{}': {}", day, why))
+                .and_then(|day| {
+                    scaffold::scaffold(&workspace(), day).map_err(|why| why.to_string())
+                });
+
+            match created {
+                Ok(dir) => {
+                    println!("created {}", dir.display());
+                    true
+                }
+                Err(why) => {
+                    eprintln!("{}", why);
+                    false
+                }
+            }
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            false
+        }
+    };
+
+    if !success {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,281 @@
+//! Creating the crate for a new day from `_template`.
+//!
+//! The template is copied to `NN/` with its crate name, `Day00` and `DAY`
+//! replaced. The new crate is then added to the workspace members, to the
+//! runner's dependencies and to its registry in `days.rs`.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("day must be between 1 and 25, not {0}")]
+    InvalidDay(u8),
+    #[error("{} already exists", .0.display())]
+    Exists(PathBuf),
+    #[error("couldn't read {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("couldn't write {}: {source}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+    #[error("found no place for day {day} in {}", path.display())]
+    NoInsertionPoint { path: PathBuf, day: u8 },
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Read {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|source| ScaffoldError::Write {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// The template's text with its names replaced by the new day's.
+fn instantiate(template: &str, day: u8) -> String {
+    template
+        .replace("aoc-2020-template", &format!("aoc-2020-{:02}", day))
+        .replace("aoc_2020_template", &format!("aoc_2020_{:02}", day))
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+}
+
+/// Copy the template directory, skipping build output.
+fn copy_template(from: &Path, to: &Path, day: u8) -> Result<(), ScaffoldError> {
+    fs::create_dir(to).map_err(|source| ScaffoldError::Write {
+        path: to.to_owned(),
+        source,
+    })?;
+
+    let entries = fs::read_dir(from).map_err(|source| ScaffoldError::Read {
+        path: from.to_owned(),
+        source,
+    })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|source| ScaffoldError::Read {
+            path: from.to_owned(),
+            source,
+        })?;
+        let (source, target) = (entry.path(), to.join(entry.file_name()));
+
+        if source.is_dir() {
+            if entry.file_name() != "target" {
+                copy_template(&source, &target, day)?;
+            }
+        } else {
+            write(&target, &instantiate(&read(&source)?, day))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The day a line refers to, if it starts with `prefix` followed by the
+/// two-digit day.
+fn day_of(line: &str, prefix: &str) -> Option<u8> {
+    line.trim_start()
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.get(..2))
+        .and_then(|day| day.parse().ok())
+}
+
+/// Insert `new_line` into the list of lines for each day starting with
+/// `prefix`, keeping the list ordered by day.
+fn insert_line(text: &str, prefix: &str, day: u8, new_line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line, prefix).map(|d| (i, d)))
+        .collect();
+
+    let position = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => days.last()?.0 + 1,
+    };
+
+    let mut result: Vec<&str> = lines[..position].to_vec();
+    result.push(new_line);
+    result.extend_from_slice(&lines[position..]);
+
+    Some(result.join("\n") + "\n")
+}
+
+/// The file at `path` with `new_line` inserted, without writing it yet.
+fn register(
+    path: PathBuf,
+    prefix: &str,
+    day: u8,
+    new_line: &str,
+) -> Result<(PathBuf, String), ScaffoldError> {
+    let text = read(&path)?;
+    match insert_line(&text, prefix, day, new_line) {
+        Some(updated) => Ok((path, updated)),
+        None => Err(ScaffoldError::NoInsertionPoint { path, day }),
+    }
+}
+
+/// Write all files, or if one fails, put back those already written.
+fn write_all(files: &[(PathBuf, String)]) -> Result<(), ScaffoldError> {
+    let mut originals = Vec::new();
+    for (path, contents) in files {
+        let written = read(path).and_then(|original| {
+            write(path, contents)?;
+            originals.push((path, original));
+            Ok(())
+        });
+
+        if let Err(why) = written {
+            for (path, original) in originals {
+                let _ = fs::write(path, original);
+            }
+            return Err(why);
+        }
+    }
+
+    Ok(())
+}
+
+/// Create `NN/` in the workspace at `root` and register it everywhere.
+///
+/// All edits are worked out before anything is written. If a step fails,
+/// `NN/` is removed again and the edited files are restored, so the
+/// workspace still builds.
+pub fn scaffold(root: &Path, day: u8) -> Result<PathBuf, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let dir = root.join(format!("{:02}", day));
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    let files = [
+        register(
+            root.join("Cargo.toml"),
+            "\"",
+            day,
+            &format!("  \"{:02}\",", day),
+        )?,
+        register(
+            root.join("aoc").join("Cargo.toml"),
+            "aoc-2020-",
+            day,
+            &format!("aoc-2020-{:02} = {{ path = \"../{:02}\" }}", day, day),
+        )?,
+        register(
+            root.join("aoc").join("src").join("days.rs"),
+            "aoc_2020_",
+            day,
+            &format!("    aoc_2020_{:02}::Day{:02},", day, day),
+        )?,
+    ];
+
+    let created =
+        copy_template(&root.join("_template"), &dir, day).and_then(|()| write_all(&files));
+    if let Err(why) = created {
+        let _ = fs::remove_dir_all(&dir);
+        return Err(why);
+    }
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_template() {
+        let template =
+            "use aoc_2020_template::Day00;\nconst DAY: u8 = 0;\nname = \"aoc-2020-template\"";
+        assert_eq!(
+            instantiate(template, 18),
+            "use aoc_2020_18::Day18;\nconst DAY: u8 = 18;\nname = \"aoc-2020-18\""
+        );
+    }
+
+    #[test]
+    fn inserts_in_order() {
+        let members = "members = [\n  \"aoc\",\n  \"07\",\n  \"09\",\n]\n";
+        assert_eq!(
+            insert_line(members, "\"", 8, "  \"08\","),
+            Some("members = [\n  \"aoc\",\n  \"07\",\n  \"08\",\n  \"09\",\n]\n".to_owned())
+        );
+        assert_eq!(
+            insert_line(members, "\"", 18, "  \"18\","),
+            Some("members = [\n  \"aoc\",\n  \"07\",\n  \"09\",\n  \"18\",\n]\n".to_owned())
+        );
+        assert_eq!(insert_line("members = []\n", "\"", 1, "  \"01\","), None);
+    }
+
+    /// A workspace in the temporary directory whose runner has no
+    /// dependency on any day, so there's nowhere to register a new one.
+    fn broken_workspace(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("_template").join("src")).unwrap();
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+
+        fs::write(
+            root.join("_template").join("src").join("lib.rs"),
+            "pub struct Day00;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "members = [\n  \"aoc\",\n  \"01\",\n]\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "    aoc_2020_01::Day01,\n",
+        )
+        .unwrap();
+
+        root
+    }
+
+    #[test]
+    fn leaves_workspace_alone_on_failure() {
+        let root = broken_workspace("failure");
+
+        assert!(matches!(
+            scaffold(&root, 18),
+            Err(ScaffoldError::NoInsertionPoint { day: 18, .. })
+        ));
+        assert!(!root.join("18").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "members = [\n  \"aoc\",\n  \"01\",\n]\n"
+        );
+
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "aoc-2020-01 = { path = \"../01\" }\n",
+        )
+        .unwrap();
+        fs::remove_dir_all(root.join("_template")).unwrap();
+        assert!(matches!(
+            scaffold(&root, 18),
+            Err(ScaffoldError::Read { .. })
+        ));
+        assert!(!root.join("18").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "members = [\n  \"aoc\",\n  \"01\",\n]\n"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{Answer, Part};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Known-good answers of a day, read from an `answers.toml` next to its
+/// `input.txt`:
+///
+/// ```toml
+/// part1 = 313
+/// part2 = 2640
+/// ```
+///
+/// Either part may be missing if its answer isn't known yet. Values can be
+/// integers or strings.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KnownAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("couldn't read {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("couldn't parse {}: {source}", path.display())]
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("{}: '{key}' must be an integer or a string", path.display())]
+    InvalidValue { path: PathBuf, key: String },
+}
+
+/// Outcome of comparing an answer with the known-good one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl KnownAnswers {
+    /// Read the answers file at `path`; a missing file means that no answers
+    /// are known.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<KnownAnswers, AnswersError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => KnownAnswers::parse(&text, path),
+            Err(why) if why.kind() == io::ErrorKind::NotFound => Ok(KnownAnswers::default()),
+            Err(source) => Err(AnswersError::Read {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    /// Parse the contents of the answers file at `path`.
+    pub fn parse(text: &str, path: &Path) -> Result<KnownAnswers, AnswersError> {
+        let table = text
+            .parse::<toml::Value>()
+            .map_err(|source| AnswersError::Toml {
+                path: path.to_owned(),
+                source,
+            })?;
+
+        let value = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(toml::Value::Integer(n)) => Ok(Some(n.to_string())),
+            Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+            Some(_) => Err(AnswersError::InvalidValue {
+                path: path.to_owned(),
+                key: key.to_owned(),
+            }),
+        };
+
+        Ok(KnownAnswers {
+            part1: value("part1")?,
+            part2: value("part2")?,
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_integers_and_strings() {
+        let known = KnownAnswers::parse("part1 = 313\npart2 = \"abc\"\n", Path::new("a")).unwrap();
+        assert_eq!(known.get(Part::One), Some("313"));
+        assert_eq!(known.get(Part::Two), Some("abc"));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let known = KnownAnswers::parse("part1 = 313\n", Path::new("a")).unwrap();
+        assert_eq!(
+            known.check(Part::One, &Answer::Unsigned(313)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            known.check(Part::One, &Answer::Signed(-313)),
+            Verdict::Fail {
+                expected: "313".to_owned()
+            }
+        );
+        assert_eq!(
+            known.check(Part::Two, &Answer::Unsigned(1)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn rejects_other_values() {
+        assert!(matches!(
+            KnownAnswers::parse("part1 = 1.5", Path::new("a")),
+            Err(AnswersError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            KnownAnswers::parse("part1 = ", Path::new("a")),
+            Err(AnswersError::Toml { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_file_means_unknown() {
+        let known = KnownAnswers::load("does/not/exist/answers.toml").unwrap();
+        assert_eq!(known, KnownAnswers::default());
+    }
+}
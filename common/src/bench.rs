@@ -0,0 +1,70 @@
+use crate::{Error, Part, Solution};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// A separately timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/// Durations of all runs of one stage.
+#[derive(Debug, Clone)]
+pub struct Samples {
+    pub stage: Stage,
+    pub durations: Vec<Duration>,
+}
+
+/// Parse `input` and solve both parts `runs` times, timing each stage of
+/// every run. Malformed lines are left out like in lenient mode, without
+/// reporting them. Parts that aren't implemented yet are left out.
+pub fn bench<S: Solution>(input: &str, runs: usize) -> Result<Vec<Samples>, Error> {
+    let stages = [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)];
+    let mut samples: Vec<Samples> = stages
+        .iter()
+        .map(|&stage| Samples {
+            stage,
+            durations: Vec::with_capacity(runs),
+        })
+        .collect();
+    let mut implemented = [true; 2];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let (parsed, _) = black_box(S::parse_lenient(black_box(input))?);
+        samples[0].durations.push(start.elapsed());
+
+        for (i, &part) in Part::ALL.iter().enumerate() {
+            if !implemented[i] {
+                continue;
+            }
+
+            let start = Instant::now();
+            let answer = S::solve(&parsed, part);
+            let duration = start.elapsed();
+
+            match answer {
+                Ok(answer) => {
+                    black_box(answer);
+                    samples[i + 1].durations.push(duration);
+                }
+                Err(Error::NotImplemented) => implemented[i] = false,
+                Err(why) => return Err(why),
+            }
+        }
+    }
+
+    samples.retain(|s| !s.durations.is_empty());
+    Ok(samples)
+}
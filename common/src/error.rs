@@ -0,0 +1,28 @@
+use crate::InputError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Input(#[from] InputError),
+    /// the input couldn't be parsed; `line` starts at 1
+    #[error("line {line}: {message}")]
+    Parse { line: usize, message: String },
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+    #[error("no solution: {0}")]
+    NoSolution(String),
+    #[error("not implemented yet")]
+    NotImplemented,
+    #[error("{0}")]
+    InvalidArgument(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, message: impl ToString) -> Error {
+        Error::Parse {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::fs::File;
+use std::io::{self, prelude::*};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone)]
+pub enum Input {
+    /// read the input from a file
+    File(PathBuf),
+    /// read the input from standard input until EOF
+    Stdin,
+    /// use a string that's compiled into the binary, e.g. via `include_str!`
+    Embedded(&'static str),
+}
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("couldn't open {}: {source}", path.display())]
+    Open { path: PathBuf, source: io::Error },
+    #[error("couldn't read {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("couldn't read from stdin: {0}")]
+    Stdin(#[source] io::Error),
+}
+
+impl Input {
+    /// Load the whole input into a string.
+    pub fn load(&self) -> Result<String, InputError> {
+        match self {
+            Input::File(path) => {
+                let mut file = File::open(path).map_err(|source| InputError::Open {
+                    path: path.clone(),
+                    source,
+                })?;
+
+                let mut input = String::new();
+                file.read_to_string(&mut input)
+                    .map_err(|source| InputError::Read {
+                        path: path.clone(),
+                        source,
+                    })?;
+
+                Ok(input)
+            }
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+
+                Ok(input)
+            }
+            Input::Embedded(text) => Ok((*text).to_owned()),
+        }
+    }
+}
+
+/// Read the file at `path` into a string.
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    Input::File(path.as_ref().to_owned()).load()
+}
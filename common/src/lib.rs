@@ -0,0 +1,28 @@
+//! Shared helpers for the Advent of Code 2020 solutions.
+
+use std::fmt::Display;
+use std::process;
+
+mod answers;
+mod bench;
+mod error;
+mod input;
+mod output;
+mod rejected;
+mod solution;
+
+pub use answers::{AnswersError, KnownAnswers, Verdict};
+pub use bench::{bench, Samples, Stage};
+pub use error::Error;
+pub use input::{read_input, Input, InputError};
+pub use output::{json_string, set_verbosity, to_json, verbosity, Format};
+pub use rejected::{rejected_summary, Parsing, Rejected};
+pub use solution::{
+    run, run_with_args, solve, solve_part, Answer, Part, PartReport, Report, Solution,
+};
+
+/// Print an error for the given day to stderr and exit with a failure code.
+pub fn fail(day: u8, error: impl Display) -> ! {
+    eprintln!("day {:02}: {}", day, error);
+    process::exit(1)
+}
@@ -0,0 +1,162 @@
+//! How reports are printed, and how much debug output there is.
+
+use crate::{Answer, Error, PartReport, Rejected, Report};
+use std::fmt::Write;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Output format for the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// one line per part, for people
+    Text,
+    /// one object per day with answers and timings, for machines
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Format, Error> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(Error::InvalidArgument(format!("unknown format '{}'", s))),
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// Set how much debug output to print: 0 is none, 1 prints intermediate
+/// results, 2 also prints the input.
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Print to stderr if debug output is enabled, so it never gets mixed into
+/// the answers on stdout.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::verbosity() > 0 {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// `s` as a quoted JSON string.
+pub fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+fn json_part(report: &PartReport) -> String {
+    let result = match &report.answer {
+        Ok(Answer::Unsigned(n)) => format!("\"answer\":{}", n),
+        Ok(Answer::Signed(n)) => format!("\"answer\":{}", n),
+        Ok(Answer::Text(s)) => format!("\"answer\":{}", json_string(s)),
+        Err(why) => format!("\"error\":{}", json_string(&why.to_string())),
+    };
+
+    format!(
+        "{{\"part\":{},{},\"time_ns\":{}}}",
+        report.part,
+        result,
+        report.time.as_nanos()
+    )
+}
+
+fn json_rejected(rejected: &Rejected) -> String {
+    let column = match rejected.column {
+        Some(column) => format!("\"column\":{},", column),
+        None => String::new(),
+    };
+
+    format!(
+        "{{\"line\":{},{}\"message\":{}}}",
+        rejected.line,
+        column,
+        json_string(&rejected.message)
+    )
+}
+
+/// A day's report as a single line of JSON. A part that failed has an
+/// `error` message instead of an `answer`; lines skipped while parsing are
+/// listed under `rejected`, with a `column` if the day knows it.
+pub fn to_json(report: &Report) -> String {
+    let rejected: Vec<String> = report.rejected.iter().map(json_rejected).collect();
+    let parts: Vec<String> = report.parts.iter().map(json_part).collect();
+
+    format!(
+        "{{\"day\":{},\"parse_time_ns\":{},\"rejected\":[{}],\"parts\":[{}]}}",
+        report.day,
+        report.parse_time.as_nanos(),
+        rejected.join(","),
+        parts.join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+    use std::time::Duration;
+
+    #[test]
+    fn renders_report() {
+        let report = Report {
+            day: 7,
+            parse_time: Duration::from_nanos(100),
+            rejected: vec![
+                Rejected::new(2, "not a number").at_column(3),
+                Rejected::new(5, "empty"),
+            ],
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    answer: Ok(Answer::Signed(-3)),
+                    time: Duration::from_nanos(20),
+                },
+                PartReport {
+                    part: Part::Two,
+                    answer: Err(Error::NotImplemented),
+                    time: Duration::from_nanos(1),
+                },
+            ],
+        };
+
+        assert_eq!(
+            to_json(&report),
+            "{\"day\":7,\"parse_time_ns\":100,\"rejected\":[\
+             {\"line\":2,\"column\":3,\"message\":\"not a number\"},\
+             {\"line\":5,\"message\":\"empty\"}],\"parts\":[\
+             {\"part\":1,\"answer\":-3,\"time_ns\":20},\
+             {\"part\":2,\"error\":\"not implemented yet\",\"time_ns\":1}]}"
+        );
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(json_string("a \"b\"\n\\"), "\"a \\\"b\\\"\\n\\\\\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+}
@@ -0,0 +1,112 @@
+//! Malformed lines that lenient parsing leaves out of the input.
+
+use crate::Error;
+use std::fmt;
+
+/// A line of the input that couldn't be parsed; `line` and `column` start
+/// at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejected {
+    pub line: usize,
+    /// where on the line parsing failed, if the day knows
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Rejected {
+    pub fn new(line: usize, message: impl fmt::Display) -> Rejected {
+        Rejected {
+            line,
+            column: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// The same rejection, pointing at `column` of the line.
+    pub fn at_column(self, column: usize) -> Rejected {
+        Rejected {
+            column: Some(column),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "line {}, column {}: {}", self.line, column, self.message),
+            None => write!(f, "line {}: {}", self.line, self.message),
+        }
+    }
+}
+
+/// What to do with malformed lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parsing {
+    /// fail on the first one
+    Strict,
+    /// leave them out and report them with the answers
+    Lenient,
+}
+
+impl Parsing {
+    /// The parsed input and its rejected lines, or in strict mode an error
+    /// for the first rejected line.
+    pub fn check<T>(self, parsed: (T, Vec<Rejected>)) -> Result<(T, Vec<Rejected>), Error> {
+        match (self, parsed.1.first()) {
+            (Parsing::Strict, Some(rejected)) => {
+                let message = match rejected.column {
+                    Some(column) => format!("column {}: {}", column, rejected.message),
+                    None => rejected.message.clone(),
+                };
+                Err(Error::parse(rejected.line, message))
+            }
+            _ => Ok(parsed),
+        }
+    }
+}
+
+/// Summary of the rejected lines, one per line.
+pub fn rejected_summary(rejected: &[Rejected]) -> String {
+    let mut summary = format!(
+        "rejected {} malformed line{}:\n",
+        rejected.len(),
+        if rejected.len() == 1 { "" } else { "s" }
+    );
+    for line in rejected {
+        summary.push_str(&format!("  {}\n", line));
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_only_in_strict_mode() {
+        let parsed = (vec![1, 3], vec![Rejected::new(2, "not a number")]);
+        assert!(matches!(
+            Parsing::Strict.check(parsed.clone()),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert_eq!(Parsing::Lenient.check(parsed.clone()).unwrap(), parsed);
+        assert_eq!(
+            Parsing::Strict.check((vec![1], Vec::new())).unwrap(),
+            (vec![1], Vec::new())
+        );
+    }
+
+    #[test]
+    fn summarizes_rejected_lines() {
+        assert_eq!(
+            rejected_summary(&[Rejected::new(2, "not a number")]),
+            "rejected 1 malformed line:\n  line 2: not a number\n"
+        );
+        assert_eq!(
+            rejected_summary(&[Rejected::new(2, "a").at_column(4), Rejected::new(5, "b")]),
+            "rejected 2 malformed lines:\n  line 2, column 4: a\n  line 5: b\n"
+        );
+    }
+}
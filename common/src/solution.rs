@@ -0,0 +1,259 @@
+use crate::output::{set_verbosity, to_json, Format};
+use crate::rejected::{rejected_summary, Parsing, Rejected};
+use crate::{read_input, Error};
+use std::env;
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Part, Error> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::InvalidArgument(format!("unknown part '{}'", s))),
+        }
+    }
+}
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Answer {
+        Answer::Unsigned(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Answer {
+        Answer::Unsigned(n.into())
+    }
+}
+
+impl From<u16> for Answer {
+    fn from(n: u16) -> Answer {
+        Answer::Unsigned(n.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Answer {
+        Answer::Signed(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Answer {
+        Answer::Signed(n.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+/// A day of the calendar.
+///
+/// The input is parsed once by `parse` and the result is shared by both
+/// parts, so each stage can be timed and tested on its own.
+pub trait Solution {
+    /// day of the month, starting at 1
+    const DAY: u8;
+
+    /// the puzzle input after parsing
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error>;
+
+    /// Parse, but leave out malformed lines and return them instead of
+    /// failing. Only days whose lines can be skipped on their own need to
+    /// implement this.
+    fn parse_lenient(input: &str) -> Result<(Self::Parsed, Vec<Rejected>), Error> {
+        Self::parse(input).map(|parsed| (parsed, Vec::new()))
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Error>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Error>;
+
+    fn solve(parsed: &Self::Parsed, part: Part) -> Result<Answer, Error> {
+        match part {
+            Part::One => Self::part1(parsed),
+            Part::Two => Self::part2(parsed),
+        }
+    }
+}
+
+/// Answer and running time of a single part.
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer, Error>,
+    pub time: Duration,
+}
+
+/// Parsing time and part reports of one day.
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub parse_time: Duration,
+    /// malformed lines left out in lenient mode
+    pub rejected: Vec<Rejected>,
+    pub parts: Vec<PartReport>,
+}
+
+/// Parse `input` and solve the given parts, timing each stage.
+pub fn solve<S: Solution>(input: &str, parts: &[Part], parsing: Parsing) -> Result<Report, Error> {
+    let start = Instant::now();
+    let (parsed, rejected) = parsing.check(S::parse_lenient(input)?)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(&parsed, part);
+            PartReport {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        day: S::DAY,
+        parse_time,
+        rejected,
+        parts,
+    })
+}
+
+/// Parse `input` and solve a single part.
+pub fn solve_part<S: Solution>(input: &str, part: Part) -> Result<Answer, Error> {
+    S::solve(&S::parse(input)?, part)
+}
+
+const USAGE: &str = "usage: [--strict] [--format text|json] [-v|-vv]";
+
+/// Parsing mode, output format and verbosity from a day binary's arguments.
+fn parse_args(args: impl Iterator<Item = String>) -> Result<(Parsing, Format, u8), Error> {
+    let mut parsing = Parsing::Lenient;
+    let mut format = Format::Text;
+    let mut verbosity = 0;
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strict" => parsing = Parsing::Strict,
+            "--format" => {
+                format = args
+                    .next()
+                    .ok_or_else(|| Error::InvalidArgument("--format needs a value".to_owned()))?
+                    .parse()?;
+            }
+            "-v" | "--verbose" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            _ => {
+                return Err(Error::InvalidArgument(format!(
+                    "unknown argument '{}'\n{}",
+                    arg, USAGE
+                )))
+            }
+        }
+    }
+
+    Ok((parsing, format, verbosity))
+}
+
+/// Entry point for a day's own binary: solve both parts for `input.txt` in
+/// the working directory and print the answers.
+///
+/// Malformed lines that the day can skip are listed on stderr; `--strict`
+/// fails on them instead. `--format json` prints the answers, timings and
+/// skipped lines as JSON. `-v` turns on debug output on stderr, `-vv` also
+/// prints the input.
+pub fn run<S: Solution>() {
+    run_with_args::<S>(env::args().skip(1));
+}
+
+/// Like `run`, for binaries that handle some arguments of their own and pass
+/// on the rest.
+pub fn run_with_args<S: Solution>(args: impl Iterator<Item = String>) {
+    let (parsing, format, verbosity) =
+        parse_args(args).unwrap_or_else(|why| crate::fail(S::DAY, why));
+    set_verbosity(verbosity);
+
+    let input = read_input("input.txt").unwrap_or_else(|why| crate::fail(S::DAY, why));
+    if verbosity > 1 {
+        eprintln!("{}", input);
+    }
+
+    let report =
+        solve::<S>(&input, &Part::ALL, parsing).unwrap_or_else(|why| crate::fail(S::DAY, why));
+    if format == Format::Text && !report.rejected.is_empty() {
+        eprint!("{}", rejected_summary(&report.rejected));
+    }
+
+    if format == Format::Json {
+        println!("{}", to_json(&report));
+        if report.parts.iter().any(|part| part.answer.is_err()) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    for PartReport { part, answer, .. } in report.parts {
+        match answer {
+            Ok(answer) => println!("day {:02} part {}: {}", S::DAY, part, answer),
+            Err(why) => crate::fail(S::DAY, why),
+        }
+    }
+}